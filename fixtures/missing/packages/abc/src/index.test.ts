@@ -0,0 +1,2 @@
+import { describe, it } from "vitest";
+import { z } from "zod";
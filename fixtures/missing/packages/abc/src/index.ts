@@ -0,0 +1,10 @@
+import { readFileSync } from "node:fs";
+import path from "path";
+import React from "react";
+import { z } from "zod";
+import leftPad from "left-pad";
+import { Button } from "components/Button";
+import { utils } from "@/utils";
+import { helper } from "abc/helper";
+import { Schema } from "def";
+import type { Options } from "type-fest";
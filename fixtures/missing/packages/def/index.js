@@ -0,0 +1 @@
+export { z } from "zod";
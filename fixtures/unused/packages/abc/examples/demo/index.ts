@@ -0,0 +1 @@
+import { z } from "zod";
@@ -0,0 +1 @@
+import leftPad from "left-pad";
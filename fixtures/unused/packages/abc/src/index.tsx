@@ -0,0 +1,8 @@
+import React from "react";
+import { format } from "./utils";
+
+// import { z } from "zod";
+
+export function App() {
+  return <p>Don't {format(React.version)}</p>;
+}
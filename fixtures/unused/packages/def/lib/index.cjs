@@ -0,0 +1,6 @@
+const merge = require("lodash.merge");
+
+module.exports = async () => {
+  const { QueryClient } = await import("@tanstack/react-query");
+  return merge({}, new QueryClient());
+};
@@ -43,6 +43,14 @@ pub struct Args {
     #[arg(long)]
     pub fail_on_warnings: bool,
 
+    /// Never prompt, e.g. to select a version or a package manager. Enabled automatically when stdin isn't a terminal.
+    #[arg(long)]
+    pub non_interactive: bool,
+
+    /// Report the issues of the whole monorepo, instead of only the package the command is run from.
+    #[arg(long)]
+    pub all: bool,
+
     /// Ignore the `multiple-dependency-versions` rule for the given dependency name and/or version.
     #[arg(long, short)]
     pub ignore_dependency: Vec<String>,
@@ -64,9 +72,22 @@ impl From<Args> for Config {
             select: args.select,
             no_install: args.no_install,
             fail_on_warnings: args.fail_on_warnings,
+            non_interactive: args.non_interactive,
             ignore_dependency: args.ignore_dependency,
             ignore_package: args.ignore_package,
             ignore_rule: args.ignore_rule,
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+impl Args {
+    /// The command line defaults, linting the given path.
+    pub fn for_path(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            ..Self::parse_from(["sherif"])
         }
     }
 }
@@ -1,18 +1,24 @@
+use crate::lockfile::{Lockfile, LockfileKind};
+use crate::packages::installed;
 use crate::packages::root::RootPackage;
-use crate::packages::semversion::SemVersion;
-use crate::packages::{Config, Package, PackagesList};
+use crate::packages::semversion::{SemRange, SemVersion};
+use crate::packages::sources::{is_ignored, matches_workspace_glob, Gitignore};
+use crate::packages::{matches_dependency, Config, EnginesStrictness, Package, PackagesList};
 use crate::printer::print_error;
+use crate::rules::empty_dependencies::DependencyKind;
+use crate::rules::incompatible_engines::IncompatibleEnginesIssue;
 use crate::rules::multiple_dependency_versions::MultipleDependencyVersionsIssue;
+use crate::rules::multiple_resolved_versions::MultipleResolvedVersionsIssue;
 use crate::rules::non_existant_packages::NonExistantPackagesIssue;
+use crate::rules::orphan_packages::OrphanPackagesIssue;
 use crate::rules::packages_without_package_json::PackagesWithoutPackageJsonIssue;
 use crate::rules::types_in_dependencies::TypesInDependenciesIssue;
-use crate::rules::unsync_similar_dependencies::{
-    SimilarDependency, UnsyncSimilarDependenciesIssue,
-};
+use crate::rules::unsync_similar_dependencies::UnsyncSimilarDependenciesIssue;
 use crate::rules::{BoxIssue, IssuesList, PackageType};
 use anyhow::{anyhow, Result};
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::fs::{self};
 use std::path::{Path, PathBuf};
 
@@ -49,6 +55,7 @@ pub fn collect_packages(root: &Path) -> Result<PackagesList> {
     }
 
     let mut packages_issues: Vec<BoxIssue> = Vec::new();
+    let mut nested_workspaces = Vec::new();
 
     let mut add_package = |packages_issues: &mut Vec<BoxIssue>, path: PathBuf| {
         // Ignore hidden directories, e.g. `.npm`, `.react-email`
@@ -61,6 +68,7 @@ pub fn collect_packages(root: &Path) -> Result<PackagesList> {
         }
 
         match Package::new(path.clone()) {
+            Ok(package) if is_workspace_root(&package) => nested_workspaces.push(path),
             Ok(package) => packages.push(package),
             Err(error) => {
                 if error.to_string().contains("not found") {
@@ -237,19 +245,159 @@ pub fn collect_packages(root: &Path) -> Result<PackagesList> {
         if !non_existant_paths.is_empty() {
             packages_issues.push(NonExistantPackagesIssue::new(
                 is_pnpm_workspace,
-                packages_list.unwrap(),
+                packages_list.clone().unwrap(),
                 non_existant_paths,
             ));
         }
     }
 
+    let workspace_paths = packages
+        .iter()
+        .map(|package| PathBuf::from(package.get_path()))
+        .collect::<HashSet<_>>();
+    let excluded_patterns = packages_list
+        .iter()
+        .flatten()
+        .filter_map(|package| package.strip_prefix('!'))
+        .map(|pattern| pattern.trim_start_matches("./").trim_end_matches('/'))
+        .collect::<Vec<_>>();
+    let mut orphan_paths = Vec::new();
+
+    find_orphan_packages(
+        root,
+        root,
+        &workspace_paths,
+        &excluded_patterns,
+        &mut Vec::new(),
+        &mut orphan_paths,
+        &mut nested_workspaces,
+    );
+
+    if !orphan_paths.is_empty() {
+        packages_issues.push(OrphanPackagesIssue::new(
+            is_pnpm_workspace,
+            packages_list.unwrap_or_default(),
+            orphan_paths,
+        ));
+    }
+
+    nested_workspaces.sort();
+
     Ok(PackagesList {
         root_package,
         packages,
         packages_issues,
+        nested_workspaces,
+    })
+}
+
+fn is_root(directory: &Path) -> bool {
+    directory.join(PNPM_WORKSPACE).is_file()
+        || Package::new(directory.to_path_buf()).is_ok_and(|package| package.has_workspaces())
+}
+
+/// Find the nearest monorepo root, starting from the given directory and
+/// walking up its parents.
+pub fn find_root(path: &Path) -> Option<PathBuf> {
+    let mut directory = fs::canonicalize(path).ok()?;
+
+    loop {
+        if is_root(&directory) {
+            return Some(directory);
+        }
+
+        directory = directory.parent()?.to_path_buf();
+    }
+}
+
+/// The path of the package containing the given directory, if any.
+pub fn find_scope(packages_list: &PackagesList, path: &Path) -> Option<String> {
+    let path = fs::canonicalize(path).ok()?;
+
+    packages_list.packages.iter().find_map(|package| {
+        let package_path = fs::canonicalize(package.get_path()).ok()?;
+
+        path.starts_with(package_path).then(|| package.get_path())
     })
 }
 
+/// Whether the package has its own workspace, and should be linted separately.
+fn is_workspace_root(package: &Package) -> bool {
+    package.has_workspaces()
+        || Path::new(&package.get_path())
+            .join(PNPM_WORKSPACE)
+            .is_file()
+}
+
+/// Walk the repository to find folders with a `package.json` that aren't
+/// matched by the workspace, without looking inside workspace packages.
+/// Nested workspaces are collected instead of being reported.
+fn find_orphan_packages(
+    root: &Path,
+    directory: &Path,
+    workspace_paths: &HashSet<PathBuf>,
+    excluded_patterns: &[&str],
+    gitignores: &mut Vec<Gitignore>,
+    orphan_paths: &mut Vec<String>,
+    nested_workspaces: &mut Vec<PathBuf>,
+) {
+    // Folders we can't read (e.g. permissions) can't be installed either.
+    let Ok(entries) = directory.read_dir() else {
+        return;
+    };
+
+    let gitignore = Gitignore::read(directory);
+    let has_gitignore = gitignore.is_some();
+    gitignores.extend(gitignore);
+
+    let mut directories = entries
+        .flatten()
+        .filter(|entry| entry.file_type().is_ok_and(|file_type| file_type.is_dir()))
+        .map(|entry| entry.path())
+        .collect::<Vec<_>>();
+    directories.sort();
+
+    for path in directories {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let relative = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        if name.starts_with('.')
+            || name == "node_modules"
+            || workspace_paths.contains(&path)
+            || is_ignored(gitignores, &path, true)
+            || excluded_patterns
+                .iter()
+                .any(|pattern| matches_workspace_glob(pattern, &relative))
+        {
+            continue;
+        }
+
+        match path.join("package.json").is_file() {
+            true => match Package::new(path.clone()) {
+                Ok(package) if is_workspace_root(&package) => nested_workspaces.push(path),
+                _ => orphan_paths.push(relative),
+            },
+            false => find_orphan_packages(
+                root,
+                &path,
+                workspace_paths,
+                excluded_patterns,
+                gitignores,
+                orphan_paths,
+                nested_workspaces,
+            ),
+        }
+    }
+
+    if has_gitignore {
+        gitignores.pop();
+    }
+}
+
 pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesList<'_> {
     let mut issues = IssuesList::new(&config.ignore_rule);
 
@@ -257,14 +405,146 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
         root_package,
         packages,
         packages_issues,
+        ..
     } = packages_list;
 
     for package_issue in packages_issues {
         issues.add_raw(PackageType::None, package_issue);
     }
 
+    let root_path = PathBuf::from(root_package.get_path());
+    let plug_n_play = installed::is_plug_n_play(&root_path);
+    // Rules reading `node_modules` are opt-in, so default runs only read manifests.
+    let check_installed = config.check_installed && !plug_n_play;
+
+    if check_installed {
+        issues.add(
+            PackageType::Root,
+            root_package.check_installed(&root_path, &config.ignore_dependency),
+        );
+        issues.add(
+            PackageType::Root,
+            root_package.check_types_packages(&root_path),
+        );
+    }
+
+    // The first version declared for each dependency across the workspace.
+    let mut workspace_versions = root_package.get_locked_specifiers();
+    // All ranges declared for each dependency, with the package declaring them.
+    let mut declared_dependencies = IndexMap::<String, Vec<(String, String)>>::new();
+
+    for (name, version) in root_package.get_locked_specifiers() {
+        declared_dependencies
+            .entry(name)
+            .or_default()
+            .push((root_package.get_path(), version));
+    }
+
+    for package in &packages {
+        for (name, version) in package.get_locked_specifiers() {
+            workspace_versions
+                .entry(name.clone())
+                .or_insert(version.clone());
+            declared_dependencies
+                .entry(name)
+                .or_default()
+                .push((package.get_path(), version));
+        }
+    }
+
+    // Workspace packages no one depends on yet are linked with the workspace
+    // protocol, which npm and Yarn classic don't support.
+    let workspace_protocol = match root_package.get_package_manager() {
+        Some(package_manager) => {
+            match package_manager.starts_with("npm@") || package_manager.starts_with("yarn@1.") {
+                true => "*",
+                false => "workspace:*",
+            }
+        }
+        None => match Lockfile::find(&root_path) {
+            Some(LockfileKind::Npm) => "*",
+            _ => "workspace:*",
+        },
+    };
+
+    for package in &packages {
+        if let Some(name) = package.get_name() {
+            workspace_versions
+                .entry(name.clone())
+                .or_insert_with(|| workspace_protocol.to_string());
+        }
+    }
+
+    let lockfile = Lockfile::read(&root_path);
+    let workspace_packages = packages
+        .iter()
+        .filter_map(|package| package.get_name().clone())
+        .collect::<HashSet<_>>();
+
+    let mut resolved_versions = IndexMap::<String, IndexMap<String, Vec<String>>>::new();
+    let mut add_resolved_versions = |path: String, versions: Vec<(String, String)>| {
+        for (name, version) in versions {
+            resolved_versions
+                .entry(name)
+                .or_default()
+                .entry(version)
+                .or_default()
+                .push(path.clone());
+        }
+    };
+
+    if let Some((lockfile_kind, lockfile)) = &lockfile {
+        issues.add(
+            PackageType::Root,
+            root_package.check_lockfile(&root_path, *lockfile_kind, lockfile, &workspace_packages),
+        );
+
+        if let Some(config_resolved_versions) = config.resolved_versions {
+            add_resolved_versions(
+                root_package.get_path(),
+                root_package.get_resolved_versions(&root_path, lockfile, config_resolved_versions),
+            );
+        }
+    }
+
+    issues.add(
+        PackageType::Root,
+        root_package.check_overrides(&declared_dependencies),
+    );
+
+    // Lockfiles without a package graph (e.g. npm v1) can't tell which
+    // transitive dependencies are still used.
+    if let Some((_, lockfile)) = lockfile
+        .as_ref()
+        .filter(|(_, lockfile)| !lockfile.packages.is_empty())
+    {
+        let mut used_dependencies = lockfile
+            .packages
+            .values()
+            .map(|package| package.name.as_str())
+            .collect::<HashSet<_>>();
+        used_dependencies.extend(
+            packages
+                .iter()
+                .flat_map(|package| package.get_peer_dependencies())
+                .chain(root_package.get_peer_dependencies())
+                .flat_map(|peer_dependencies| peer_dependencies.keys())
+                .map(String::as_str),
+        );
+
+        issues.add(
+            PackageType::Root,
+            root_package.check_unused_overrides(&declared_dependencies, &used_dependencies),
+        );
+    }
+
     issues.add(PackageType::Root, root_package.check_private());
     issues.add(PackageType::Root, root_package.check_package_manager());
+    issues.add(
+        PackageType::Root,
+        root_package.check_package_manager_value(&root_path),
+    );
+
     issues.add(PackageType::Root, root_package.check_dependencies());
     issues.add(PackageType::Root, root_package.check_dev_dependencies());
     issues.add(PackageType::Root, root_package.check_peer_dependencies());
@@ -273,20 +553,72 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
         root_package.check_optional_dependencies(),
     );
 
+    for issue in root_package.check_banned_dependencies(&config.banned_dependencies) {
+        issues.add_raw(PackageType::Root, issue);
+    }
+
+    for dependency_kind in DependencyKind::ALL {
+        if let Some(style) = root_package.get_range_style(config, &dependency_kind) {
+            issues.add(
+                PackageType::Root,
+                root_package.check_range_style(dependency_kind, style, &config.ignore_dependency),
+            );
+        }
+    }
+
+    let key_order = config
+        .key_order
+        .as_ref()
+        .map(|key_order| key_order.get_order());
+
+    if let Some(key_order) = &key_order {
+        issues.add(PackageType::Root, root_package.check_key_order(key_order));
+    }
+
+    let root_package_manager = root_package.get_package_manager().cloned();
     let mut all_dependencies = IndexMap::new();
+    let mut all_peer_dependencies = IndexMap::<String, IndexMap<String, String>>::new();
     let mut joined_dependencies = IndexMap::new();
+    let mut dependency_kinds = HashMap::new();
     let mut similar_dependencies_by_package = IndexMap::new();
+    let mut packages_engines = Vec::new();
 
     if let Some(dependencies) = root_package.get_dependencies() {
-        joined_dependencies.extend(dependencies);
+        joined_dependencies.extend(
+            dependencies
+                .into_iter()
+                .map(|(name, version)| (name, (version, DependencyKind::Dependencies))),
+        );
     }
 
     if let Some(dev_dependencies) = root_package.get_dev_dependencies() {
-        joined_dependencies.extend(dev_dependencies);
+        joined_dependencies.extend(
+            dev_dependencies
+                .into_iter()
+                .map(|(name, version)| (name, (version, DependencyKind::DevDependencies))),
+        );
+    }
+
+    if let Some(optional_dependencies) = root_package.get_optional_dependencies() {
+        joined_dependencies.extend(
+            optional_dependencies
+                .into_iter()
+                .map(|(name, version)| (name, (version, DependencyKind::OptionalDependencies))),
+        );
+    }
+
+    if let Some(peer_dependencies) = root_package.get_peer_dependencies() {
+        for (name, range) in peer_dependencies {
+            all_peer_dependencies
+                .entry(name.clone())
+                .or_default()
+                .insert(root_package.get_path(), range.clone());
+        }
     }
 
-    for (name, version) in joined_dependencies {
+    for (name, (version, dependency_kind)) in joined_dependencies {
         if version.is_valid() {
+            dependency_kinds.insert((root_package.get_path(), name.clone()), dependency_kind);
             all_dependencies
                 .entry(name)
                 .or_insert_with(IndexMap::new)
@@ -305,6 +637,117 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
         issues.add(package_type.clone(), package.check_dev_dependencies());
         issues.add(package_type.clone(), package.check_peer_dependencies());
         issues.add(package_type.clone(), package.check_optional_dependencies());
+        // Targets usually don't exist before building, so they're only checked
+        // with the other entrypoints of published packages.
+        let check_exports_files = !package.is_private()
+            && config
+                .publish
+                .as_ref()
+                .is_some_and(|publish| publish.entrypoints);
+        issues.add(
+            package_type.clone(),
+            package.check_exports(check_exports_files),
+        );
+        issues.add(package_type.clone(), package.check_non_root_overrides());
+
+        if let Some(key_order) = &key_order {
+            issues.add(package_type.clone(), package.check_key_order(key_order));
+        }
+
+        if let Some(root_package_manager) = &root_package_manager {
+            issues.add(
+                package_type.clone(),
+                package.check_package_manager_conflict(root_package_manager),
+            );
+        }
+
+        if check_installed {
+            issues.add(
+                package_type.clone(),
+                package.check_installed(&root_path, &config.ignore_dependency),
+            );
+            issues.add(
+                package_type.clone(),
+                package.check_types_packages(&root_path),
+            );
+        }
+
+        if config.unused_dependencies.is_some() || config.missing_dependencies.is_some() {
+            let imports = package.get_imports(&root_path);
+
+            if let Some(unused_dependencies) = &config.unused_dependencies {
+                issues.add(
+                    package_type.clone(),
+                    package.check_unused_dependencies(&imports, &unused_dependencies.allow),
+                );
+            }
+
+            if let Some(missing_dependencies) = &config.missing_dependencies {
+                issues.add(
+                    package_type.clone(),
+                    package.check_missing_dependencies(
+                        &imports,
+                        &missing_dependencies.allow,
+                        &workspace_versions,
+                    ),
+                );
+            }
+        }
+
+        if let Some((lockfile_kind, lockfile)) = &lockfile {
+            issues.add(
+                package_type.clone(),
+                package.check_lockfile(&root_path, *lockfile_kind, lockfile, &workspace_packages),
+            );
+
+            if let Some(config_resolved_versions) = config.resolved_versions {
+                add_resolved_versions(
+                    package.get_path(),
+                    package.get_resolved_versions(&root_path, lockfile, config_resolved_versions),
+                );
+            }
+        }
+
+        for issue in package.check_banned_dependencies(&config.banned_dependencies) {
+            issues.add_raw(package_type.clone(), issue);
+        }
+
+        for dependency_kind in DependencyKind::ALL {
+            if let Some(style) = config.get_range_style(&package, &dependency_kind) {
+                issues.add(
+                    package_type.clone(),
+                    package.check_range_style(dependency_kind, style, &config.ignore_dependency),
+                );
+            }
+        }
+
+        if let Some(publish) = &config.publish {
+            if !package.is_private() {
+                if publish.version {
+                    issues.add(package_type.clone(), package.check_version());
+                }
+
+                if publish.license {
+                    issues.add(package_type.clone(), package.check_license());
+                }
+
+                if publish.repository {
+                    issues.add(package_type.clone(), package.check_repository(&root_path));
+                }
+
+                if publish.files {
+                    issues.add(package_type.clone(), package.check_files());
+                }
+
+                if publish.entrypoints {
+                    issues.add(package_type.clone(), package.check_entrypoints());
+                }
+            }
+        }
+
+        if let Some(engines) = package.get_engines() {
+            packages_engines.push((package.get_path(), engines));
+        }
 
         let mut joined_dependencies = IndexMap::new();
 
@@ -324,15 +767,41 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
                 }
             }
 
-            joined_dependencies.extend(dependencies);
+            joined_dependencies.extend(
+                dependencies
+                    .into_iter()
+                    .map(|(name, version)| (name, (version, DependencyKind::Dependencies))),
+            );
         }
 
         if let Some(dev_dependencies) = package.get_dev_dependencies() {
-            joined_dependencies.extend(dev_dependencies);
+            joined_dependencies.extend(
+                dev_dependencies
+                    .into_iter()
+                    .map(|(name, version)| (name, (version, DependencyKind::DevDependencies))),
+            );
+        }
+
+        if let Some(optional_dependencies) = package.get_optional_dependencies() {
+            joined_dependencies.extend(
+                optional_dependencies
+                    .into_iter()
+                    .map(|(name, version)| (name, (version, DependencyKind::OptionalDependencies))),
+            );
+        }
+
+        if let Some(peer_dependencies) = package.get_peer_dependencies() {
+            for (name, range) in peer_dependencies {
+                all_peer_dependencies
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(package.get_path(), range.clone());
+            }
         }
 
-        for (name, version) in joined_dependencies {
+        for (name, (version, dependency_kind)) in joined_dependencies {
             if version.is_valid() {
+                dependency_kinds.insert((package.get_path(), name.clone()), dependency_kind);
                 all_dependencies
                     .entry(name)
                     .or_insert_with(IndexMap::new)
@@ -341,15 +810,34 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
         }
     }
 
+    let similar_dependencies = config.similar_dependencies.get_groups();
+    let mut similar_dependencies_in_workspace = IndexMap::new();
+
     for (name, versions) in all_dependencies {
-        if let Ok(similar_dependency) = SimilarDependency::try_from(name.as_str()) {
+        if let Some(similar_dependency) = similar_dependencies
+            .iter()
+            .find(|similar_dependency| similar_dependency.matches(&name))
+        {
+            if config.similar_dependencies.workspace {
+                for (path, version) in versions.iter() {
+                    let dependency_kind = dependency_kinds[&(path.clone(), name.clone())];
+
+                    similar_dependencies_in_workspace
+                        .entry(similar_dependency.name.clone())
+                        .or_insert_with(
+                            IndexMap::<(String, SemVersion, DependencyKind), Vec<String>>::new,
+                        )
+                        .entry((name.clone(), version.clone(), dependency_kind))
+                        .or_insert_with(Vec::new)
+                        .push(path.clone());
+                }
+            }
+
             for (path, version) in versions.iter() {
                 similar_dependencies_by_package
                     .entry(path.clone())
-                    .or_insert_with(
-                        IndexMap::<SimilarDependency, IndexMap<SemVersion, String>>::new,
-                    )
-                    .entry(similar_dependency.clone())
+                    .or_insert_with(IndexMap::<String, IndexMap<SemVersion, String>>::new)
+                    .entry(similar_dependency.name.clone())
                     .or_insert_with(IndexMap::new)
                     .insert(version.clone(), name.clone());
             }
@@ -365,27 +853,40 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
             .map(|(path, version)| (path.clone(), version.clone()))
             .collect::<IndexMap<_, _>>();
 
-        if filtered_versions.len() > 1
+        // Peer ranges only need to accept the versions used in the workspace.
+        let mut incompatible_peers = all_peer_dependencies
+            .get(&name)
+            .into_iter()
+            .flatten()
+            .filter(|(_, range)| {
+                !config
+                    .ignore_dependency
+                    .contains(&format!("{}@{}", name, range))
+            })
+            .filter(|(_, range)| match SemRange::parse(range) {
+                Ok(range) => filtered_versions.values().any(|version| {
+                    SemRange::parse(&version.to_string())
+                        .is_ok_and(|version| !range.intersects(&version))
+                }),
+                Err(_) => false,
+            })
+            .map(|(path, range)| (path.clone(), range.clone()))
+            .collect::<IndexMap<_, _>>();
+
+        if ((filtered_versions.len() > 1
             && !filtered_versions
                 .values()
                 .collect::<Vec<_>>()
                 .windows(2)
-                .all(|window| window[0] == window[1])
-            && !config.ignore_dependency.contains(&name)
-            && !config.ignore_dependency.iter().any(|dependency| {
-                if dependency.ends_with('*') {
-                    if dependency.starts_with('*') {
-                        return name
-                            .contains(dependency.trim_start_matches('*').trim_end_matches('*'));
-                    }
-                    return name.starts_with(dependency.trim_end_matches('*'));
-                } else if dependency.starts_with('*') {
-                    return name.ends_with(dependency.trim_start_matches('*'));
-                }
-                false
-            })
+                .all(|window| window[0] == window[1]))
+            || (!filtered_versions.is_empty() && !incompatible_peers.is_empty()))
+            && !config
+                .ignore_dependency
+                .iter()
+                .any(|dependency| matches_dependency(dependency, &name))
         {
             filtered_versions.sort_keys();
+            incompatible_peers.sort_keys();
 
             issues.add_raw(
                 PackageType::None,
@@ -393,22 +894,247 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
                     name,
                     filtered_versions,
                     config.select.clone(),
-                ),
+                )
+                .with_peers(incompatible_peers),
             );
         }
     }
 
-    for (path, similar_dependencies) in similar_dependencies_by_package {
-        for (similar_dependency, versions) in similar_dependencies {
-            if versions.len() > 1 {
+    if let Some((lockfile_kind, _)) = &lockfile {
+        for (name, versions) in resolved_versions {
+            let versions = versions
+                .into_iter()
+                .filter(|(version, _)| {
+                    !config
+                        .ignore_dependency
+                        .contains(&format!("{}@{}", name, version))
+                })
+                .collect::<IndexMap<_, _>>();
+
+            if versions.len() > 1
+                && !config
+                    .ignore_dependency
+                    .iter()
+                    .any(|dependency| matches_dependency(dependency, &name))
+            {
                 issues.add_raw(
-                    PackageType::Package(path.clone()),
-                    UnsyncSimilarDependenciesIssue::new(similar_dependency, versions),
+                    PackageType::None,
+                    MultipleResolvedVersionsIssue::new(name, *lockfile_kind, versions),
                 );
             }
         }
     }
 
+    let root_engines = root_package.get_engines().unwrap_or_default();
+    let is_incompatible = |range: &str, expected_range: &str| match config.engines_strictness {
+        EnginesStrictness::Exact => range.trim() != expected_range.trim(),
+        EnginesStrictness::Compatible => {
+            match (SemRange::parse(range), SemRange::parse(expected_range)) {
+                (Ok(range), Ok(expected_range)) => !range.intersects(&expected_range),
+                _ => false,
+            }
+        }
+    };
+
+    for (path, engines) in &packages_engines {
+        for (engine, range) in engines {
+            match root_engines.get(engine) {
+                Some(expected_range) => {
+                    if is_incompatible(range, expected_range) {
+                        issues.add_raw(
+                            PackageType::Package(path.clone()),
+                            IncompatibleEnginesIssue::new(
+                                engine.clone(),
+                                range.clone(),
+                                expected_range.clone(),
+                                None,
+                            ),
+                        );
+                    }
+                }
+                // Without a root range, compare packages with each other.
+                None => {
+                    let incompatible_package = packages_engines
+                        .iter()
+                        .filter(|(other_path, _)| other_path != path)
+                        .find_map(|(other_path, other_engines)| {
+                            other_engines
+                                .get(engine)
+                                .filter(|other_range| is_incompatible(range, other_range))
+                                .map(|other_range| (other_path, other_range))
+                        });
+
+                    if let Some((other_path, other_range)) = incompatible_package {
+                        issues.add_raw(
+                            PackageType::Package(path.clone()),
+                            IncompatibleEnginesIssue::new(
+                                engine.clone(),
+                                range.clone(),
+                                other_range.clone(),
+                                Some(other_path.clone()),
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    for (similar_dependency, mut dependencies) in similar_dependencies_in_workspace {
+        let versions = dependencies
+            .keys()
+            .map(|(_, version, _)| version)
+            .collect::<HashSet<_>>();
+
+        if versions.len() > 1 {
+            dependencies.sort_by(|(a, _, _), _, (b, _, _), _| a.cmp(b));
+
+            issues.add_raw(
+                PackageType::None,
+                UnsyncSimilarDependenciesIssue::new_workspace(
+                    similar_dependency,
+                    dependencies
+                        .into_iter()
+                        .map(|((name, version, dependency_kind), mut paths)| {
+                            paths.sort();
+                            (name, version, dependency_kind, paths)
+                        })
+                        .collect(),
+                ),
+            );
+        }
+    }
+
+    // The workspace-wide check already reports mixed versions within a package.
+    if !config.similar_dependencies.workspace {
+        for (path, similar_dependencies) in similar_dependencies_by_package {
+            for (similar_dependency, versions) in similar_dependencies {
+                if versions.len() > 1 {
+                    issues.add_raw(
+                        PackageType::Package(path.clone()),
+                        UnsyncSimilarDependenciesIssue::new(similar_dependency, versions),
+                    );
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// The versions of each dependency declared in a workspace, by package path.
+pub fn collect_workspace_dependencies(
+    config: &Config,
+    packages_list: &PackagesList,
+) -> IndexMap<String, IndexMap<String, SemVersion>> {
+    let mut all_dependencies = IndexMap::<String, IndexMap<String, SemVersion>>::new();
+    let root_package = &packages_list.root_package;
+    let mut add_dependencies =
+        |path: String, dependencies: Vec<Option<IndexMap<String, SemVersion>>>| {
+            for (name, version) in dependencies.into_iter().flatten().flatten() {
+                if version.is_valid() {
+                    all_dependencies
+                        .entry(name)
+                        .or_default()
+                        .insert(path.clone(), version);
+                }
+            }
+        };
+
+    add_dependencies(
+        root_package.get_path(),
+        vec![
+            root_package.get_dependencies(),
+            root_package.get_dev_dependencies(),
+            root_package.get_optional_dependencies(),
+        ],
+    );
+
+    for package in &packages_list.packages {
+        if package.is_ignored(&config.ignore_package) {
+            continue;
+        }
+
+        add_dependencies(
+            package.get_path(),
+            vec![
+                package.get_dependencies(),
+                package.get_dev_dependencies(),
+                package.get_optional_dependencies(),
+            ],
+        );
+    }
+
+    all_dependencies
+}
+
+/// Dependencies using a single version in each workspace, but different
+/// versions across workspaces. Versions differing within a workspace are
+/// already reported when linting it.
+pub fn collect_cross_workspace_issues(
+    config: &Config,
+    workspaces: Vec<IndexMap<String, IndexMap<String, SemVersion>>>,
+) -> IssuesList<'_> {
+    let mut issues = IssuesList::new(&config.ignore_rule);
+    let mut all_dependencies = IndexMap::<String, Vec<IndexMap<String, SemVersion>>>::new();
+
+    for workspace in workspaces {
+        for (name, versions) in workspace {
+            all_dependencies.entry(name).or_default().push(versions);
+        }
+    }
+
+    for (name, workspaces) in all_dependencies {
+        if config
+            .ignore_dependency
+            .iter()
+            .any(|dependency| matches_dependency(dependency, &name))
+        {
+            continue;
+        }
+
+        let workspaces = workspaces
+            .into_iter()
+            .map(|versions| {
+                versions
+                    .into_iter()
+                    .filter(|(_, version)| {
+                        !config
+                            .ignore_dependency
+                            .contains(&format!("{}@{}", name, version))
+                    })
+                    .collect::<IndexMap<_, _>>()
+            })
+            .filter(|versions| !versions.is_empty())
+            .collect::<Vec<_>>();
+
+        let Some(workspace_versions) = workspaces
+            .iter()
+            .map(|versions| {
+                let mut values = versions.values();
+                let first = values.next()?;
+
+                values.all(|version| version == first).then_some(first)
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+
+        if workspace_versions
+            .windows(2)
+            .any(|window| window[0] != window[1])
+        {
+            let mut versions = workspaces.into_iter().flatten().collect::<IndexMap<_, _>>();
+            versions.sort_keys();
+
+            issues.add_raw(
+                PackageType::None,
+                MultipleDependencyVersionsIssue::new(name, versions, config.select.clone()),
+            );
+        }
+    }
+
     issues
 }
 
@@ -416,6 +1142,9 @@ pub fn collect_issues(config: &Config, packages_list: PackagesList) -> IssuesLis
 mod test {
     use super::*;
     use crate::args::Args;
+    use crate::fix::EditPlan;
+    use crate::packages::{PublishConfig, ResolvedVersions};
+    use crate::rules::IssueLevel;
     use debugless_unwrap::DebuglessUnwrapErr;
 
     #[test]
@@ -452,6 +1181,7 @@ mod test {
             root_package,
             packages,
             packages_issues,
+            ..
         } = result.unwrap();
 
         assert_eq!(root_package.get_name(), "basic");
@@ -470,6 +1200,7 @@ mod test {
             root_package,
             packages,
             packages_issues,
+            ..
         } = result.unwrap();
 
         assert_eq!(root_package.get_name(), "pnpm");
@@ -488,6 +1219,7 @@ mod test {
             root_package,
             packages,
             packages_issues,
+            ..
         } = result.unwrap();
 
         assert_eq!(root_package.get_name(), "yarn-nohoist");
@@ -518,6 +1250,7 @@ mod test {
             root_package,
             packages,
             packages_issues,
+            ..
         } = result.unwrap();
 
         assert_eq!(root_package.get_name(), "without-package-json");
@@ -555,23 +1288,110 @@ mod test {
     }
 
     #[test]
-    fn collect_root_issues() {
-        let args = Args {
-            path: "fixtures/root-issues".into(),
-            fix: false,
-            select: None,
-            no_install: true,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
-        };
+    fn collect_packages_orphan() {
+        let root = Path::new("fixtures/orphan-packages");
+        let result = collect_packages(root);
 
-        let packages_list = collect_packages(&args.path).unwrap();
-        let config = args.into();
-        assert_eq!(packages_list.root_package.get_name(), "root-issues");
+        assert!(result.is_ok());
+        let PackagesList {
+            packages,
+            packages_issues,
+            ..
+        } = result.unwrap();
 
-        let issues = collect_issues(&config, packages_list);
+        assert_eq!(packages.len(), 1);
+        assert_eq!(packages_issues.len(), 1);
+        assert_eq!(packages_issues[0].name(), "orphan-packages");
+
+        colored::control::set_override(false);
+        let message = packages_issues[0].message();
+        assert!(message.contains(r#"+     "tools/cli","#));
+        // Re-included by a negated pattern of the `.gitignore`.
+        assert!(message.contains(r#"+     "sandbox-kept","#));
+        assert!(!message.contains("sandbox-tmp"));
+        // Ignored by the `.gitignore` of `tools`.
+        assert!(!message.contains("scratch"));
+        assert!(!message.contains(r#"+     "packages/old""#));
+        // Excluded paths are compared as a whole, and can be globs.
+        assert!(message.contains(r#"+     "tools/legacy-cli","#));
+        assert!(!message.contains(r#""tools/legacy","#));
+        assert!(!message.contains("tools/test"));
+        assert!(!message.contains("node_modules"));
+        assert!(!message.contains(".cache"));
+        assert!(!message.contains("dist"));
+        assert!(!message.contains("fixtures/app"));
+    }
+
+    #[test]
+    fn collect_nested_workspaces() {
+        let root = Path::new("fixtures/nested-workspaces");
+        let packages_list = collect_packages(root).unwrap();
+
+        assert_eq!(packages_list.packages.len(), 1);
+        assert!(packages_list.packages_issues.is_empty());
+        assert_eq!(packages_list.nested_workspaces, vec![root.join("examples")]);
+
+        let nested_packages_list = collect_packages(&packages_list.nested_workspaces[0]).unwrap();
+        assert_eq!(nested_packages_list.root_package.get_name(), "examples");
+        assert_eq!(nested_packages_list.packages.len(), 1);
+
+        let config = packages_list.root_package.get_config().unwrap();
+        let nested_config = nested_packages_list.root_package.get_config().unwrap();
+        assert!(config.nested_workspaces.cross_workspace);
+        assert_eq!(
+            collect_issues(
+                &nested_config,
+                collect_packages(&packages_list.nested_workspaces[0]).unwrap()
+            )
+            .total_len(),
+            0
+        );
+
+        let issues = collect_cross_workspace_issues(
+            &config,
+            vec![
+                collect_workspace_dependencies(&config, &packages_list),
+                collect_workspace_dependencies(&nested_config, &nested_packages_list),
+            ],
+        );
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues.get(&PackageType::None).unwrap()[0];
+        assert_eq!(issue.name(), "multiple-dependency-versions");
+
+        colored::control::set_override(false);
+        assert!(issue.message().contains("18.3.1"));
+        assert!(issue.message().contains("18.2.0"));
+    }
+
+    #[test]
+    fn find_root_from_package() {
+        let root = Path::new("fixtures/nested-workspaces");
+
+        assert_eq!(find_root(root), fs::canonicalize(root).ok());
+        assert_eq!(
+            find_root(&root.join("packages/abc")),
+            fs::canonicalize(root).ok()
+        );
+        assert_eq!(
+            find_root(&root.join("examples/apps/web")),
+            fs::canonicalize(root.join("examples")).ok()
+        );
+    }
+
+    #[test]
+    fn collect_root_issues() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/root-issues")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        assert_eq!(packages_list.root_package.get_name(), "root-issues");
+
+        let issues = collect_issues(&config, packages_list);
         assert_eq!(issues.total_len(), 4);
 
         let issues = issues.into_iter().collect::<IndexMap<_, _>>();
@@ -593,17 +1413,45 @@ mod test {
         );
     }
 
+    #[test]
+    fn collect_root_issues_fix() {
+        // Fixes should apply to the given root, not the current directory.
+        let directory = tempfile::tempdir().unwrap();
+        let root = directory.path();
+        fs::copy(
+            "fixtures/root-issues/package.json",
+            root.join("package.json"),
+        )
+        .unwrap();
+
+        let args = Args {
+            fix: true,
+            no_install: true,
+            ..Args::for_path(root)
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        let mut issues = collect_issues(&config, packages_list);
+        let files = EditPlan::default();
+        issues.fix(root, &files, false).unwrap();
+        files.commit().unwrap();
+        assert_eq!(issues.len_by_level(IssueLevel::Fixed), 1);
+
+        let value = fs::read_to_string(root.join("package.json")).unwrap();
+        let value = serde_json::from_str::<serde_json::Value>(&value).unwrap();
+        assert_eq!(value["private"], true);
+        // The package.json of the current directory is left untouched.
+        assert!(!fs::read_to_string("package.json")
+            .unwrap()
+            .contains(r#""private""#));
+    }
+
     #[test]
     fn collect_root_issues_fixed() {
         let args = Args {
-            fix: false,
-            select: None,
             no_install: true,
-            fail_on_warnings: false,
-            path: "fixtures/root-issues-fixed".into(),
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
+            ..Args::for_path("fixtures/root-issues-fixed")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -617,14 +1465,8 @@ mod test {
     #[test]
     fn collect_root_dev_engines() {
         let args = Args {
-            path: "fixtures/dev-engines".into(),
-            fix: false,
-            select: None,
             no_install: true,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
+            ..Args::for_path("fixtures/dev-engines")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -638,14 +1480,8 @@ mod test {
     #[test]
     fn collect_root_dev_engines_array() {
         let args = Args {
-            path: "fixtures/dev-engines-array".into(),
-            fix: false,
-            select: None,
             no_install: true,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
+            ..Args::for_path("fixtures/dev-engines-array")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -659,14 +1495,8 @@ mod test {
     #[test]
     fn collect_root_dev_engines_without_package_manager() {
         let args = Args {
-            path: "fixtures/dev-engines-no-package-manager".into(),
-            fix: false,
-            select: None,
             no_install: true,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
+            ..Args::for_path("fixtures/dev-engines-no-package-manager")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -689,14 +1519,8 @@ mod test {
     #[test]
     fn collect_dependencies() {
         let args = Args {
-            path: "fixtures/dependencies".into(),
-            fix: false,
-            select: None,
             no_install: true,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
+            ..Args::for_path("fixtures/dependencies")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -726,17 +1550,42 @@ mod test {
         );
     }
 
+    #[test]
+    fn collect_dependencies_scoped() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/dependencies")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let scope = find_scope(
+            &packages_list,
+            Path::new("fixtures/dependencies/packages/def"),
+        )
+        .unwrap();
+        assert_eq!(scope, "fixtures/dependencies/packages/def");
+
+        let config = args.into();
+        let mut issues = collect_issues(&config, packages_list);
+        issues.retain_package(&scope);
+
+        // Only `next` and `react` have a version mismatch involving `def`.
+        assert_eq!(issues.total_len(), 2);
+        assert_eq!(issues.len_by_level(IssueLevel::Error), 2);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issues = issues.get(&PackageType::None).unwrap();
+        assert!(issues.iter().all(|issue| issue.involves_package(&scope)));
+        assert!(issues
+            .iter()
+            .all(|issue| issue.name() == "multiple-dependency-versions"));
+    }
+
     #[test]
     fn collect_dependencies_allow() {
         let args = Args {
-            path: "fixtures/dependencies".into(),
-            fix: false,
-            select: None,
-            no_install: false,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
             ignore_dependency: vec!["next@4.5.6".to_string(), "*eslint*".to_string()],
+            ..Args::for_path("fixtures/dependencies")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -757,14 +1606,8 @@ mod test {
     #[test]
     fn collect_dependencies_without_star() {
         let args = Args {
-            path: "fixtures/dependencies-star".into(),
-            fix: false,
-            select: None,
             no_install: true,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
+            ..Args::for_path("fixtures/dependencies-star")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -788,16 +1631,7 @@ mod test {
 
     #[test]
     fn collect_dependencies_nested_star() {
-        let args = Args {
-            path: "fixtures/dependencies-nested-star".into(),
-            fix: false,
-            select: None,
-            no_install: false,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
-        };
+        let args = Args::for_path("fixtures/dependencies-nested-star");
 
         let packages_list = collect_packages(&args.path).unwrap();
         assert_eq!(
@@ -824,14 +1658,8 @@ mod test {
     #[test]
     fn collect_pnpm_glob() {
         let args = Args {
-            path: "fixtures/pnpm-glob".into(),
-            fix: false,
-            select: None,
             no_install: true,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
+            ..Args::for_path("fixtures/pnpm-glob")
         };
 
         let packages_list = collect_packages(&args.path).unwrap();
@@ -845,16 +1673,7 @@ mod test {
 
     #[test]
     fn collect_unordered_dependencies() {
-        let args = Args {
-            path: "fixtures/unordered".into(),
-            fix: false,
-            select: None,
-            no_install: false,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
-        };
+        let args = Args::for_path("fixtures/unordered");
 
         let packages_list = collect_packages(&args.path).unwrap();
         assert_eq!(packages_list.root_package.get_name(), "unordered");
@@ -881,16 +1700,7 @@ mod test {
 
     #[test]
     fn collect_unsync_similar_dependencies() {
-        let args = Args {
-            path: "fixtures/unsync".into(),
-            fix: false,
-            select: None,
-            no_install: false,
-            fail_on_warnings: false,
-            ignore_rule: Vec::new(),
-            ignore_package: Vec::new(),
-            ignore_dependency: Vec::new(),
-        };
+        let args = Args::for_path("fixtures/unsync");
 
         let packages_list = collect_packages(&args.path).unwrap();
         assert_eq!(packages_list.root_package.get_name(), "unsync");
@@ -912,4 +1722,791 @@ mod test {
             "unsync-similar-dependencies"
         );
     }
+
+    #[test]
+    fn collect_similar_dependencies_groups() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/similar-groups")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues
+            .get(&PackageType::Package(
+                "fixtures/similar-groups/packages/abc".into(),
+            ))
+            .unwrap()[0];
+        assert_eq!(issue.name(), "unsync-similar-dependencies");
+        assert_eq!(
+            issue.why(),
+            "Similar TanStack dependencies should use the same version."
+        );
+    }
+
+    #[test]
+    fn collect_similar_dependencies_workspace() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/similar-workspace")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues.get(&PackageType::None).unwrap()[0];
+        assert_eq!(issue.name(), "unsync-similar-dependencies");
+        assert_eq!(
+            issue.why(),
+            "Similar tRPC dependencies should use the same version."
+        );
+
+        colored::control::set_override(false);
+        let message = issue.message();
+        assert!(message
+            .contains(r#""@trpc/client": "10.0.0"   ← fixtures/similar-workspace/packages/abc"#));
+        assert!(message
+            .contains(r#""@trpc/server": "11.0.0"   ← fixtures/similar-workspace/packages/def"#));
+        assert!(message.contains(r#""devDependencies": {"#));
+        assert!(message
+            .contains(r#""@trpc/server": "11.0.0"   ← fixtures/similar-workspace/packages/ghi"#));
+    }
+
+    #[test]
+    fn collect_similar_dependencies_workspace_ignore_package() {
+        let args = Args {
+            no_install: true,
+            ignore_package: vec!["abc".into()],
+            ..Args::for_path("fixtures/similar-workspace")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 0);
+    }
+
+    #[test]
+    fn collect_peer_optional_dependencies() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/peer-optional")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 2);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issues = issues.get(&PackageType::None).unwrap();
+
+        colored::control::set_override(false);
+        let messages = issues
+            .iter()
+            .map(|issue| (issue.why().to_string(), issue.message()))
+            .collect::<IndexMap<_, _>>();
+
+        let react = &messages["Dependency react has peer dependency ranges incompatible with the version defined in the workspace."];
+        assert!(react.contains("abc                       ^17.0.0   ✕ incompatible peer"));
+        assert!(!react.contains("def"));
+
+        let fsevents =
+            &messages["Dependency fsevents has multiple versions defined in the workspace."];
+        assert!(fsevents.contains("abc                       2.3.2   ↓ lowest"));
+    }
+
+    #[test]
+    fn collect_overrides() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/overrides")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 3);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        colored::control::set_override(false);
+
+        let root = issues.get(&PackageType::Root).unwrap();
+        assert_eq!(root[0].name(), "invalid-overrides");
+        assert!(root[0].message().contains(
+            r#""react": "17.0.2"   ← but fixtures/overrides/packages/abc declares ^18.2.0."#
+        ));
+        assert!(!root[0].message().contains("foo@<2"));
+        assert_eq!(root[1].name(), "unused-overrides");
+        assert!(root[1].message().contains(r#""left-pad": "1.3.0""#));
+        assert!(!root[1].message().contains("lodash"));
+
+        assert_eq!(
+            issues
+                .get(&PackageType::Package(
+                    "fixtures/overrides/packages/def".into()
+                ))
+                .unwrap()[0]
+                .name(),
+            "invalid-overrides"
+        );
+    }
+
+    #[test]
+    fn collect_publish() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/publish")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "publish");
+        assert_eq!(packages_list.packages.len(), 3);
+
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 5);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let package_issues = issues
+            .get(&PackageType::Package(
+                "fixtures/publish/packages/invalid".to_string(),
+            ))
+            .unwrap();
+
+        assert_eq!(package_issues[0].name(), "package-version-field");
+        assert_eq!(package_issues[1].name(), "package-license-field");
+        assert_eq!(package_issues[2].name(), "package-repository-field");
+        assert_eq!(package_issues[3].name(), "package-files-field");
+        assert_eq!(package_issues[4].name(), "non-existant-entrypoints");
+
+        colored::control::set_override(false);
+        assert!(package_issues[3].message().contains(r#""files": "dist""#));
+
+        // `types` only resolves to `.d.ts` files, while `lib/index.js` exists.
+        let message = package_issues[4].message();
+        assert!(message.contains(r#""main": "./dist/index.js""#));
+        assert!(message.contains(r#""module": ["./dist/index.mjs"]"#));
+        assert!(message.contains(r#""types": "./lib/index""#));
+    }
+
+    #[test]
+    fn collect_exports() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/exports")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "exports");
+        assert_eq!(packages_list.packages.len(), 3);
+
+        let mut config: Config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues
+            .get(&PackageType::Package(
+                "fixtures/exports/packages/invalid".to_string(),
+            ))
+            .unwrap()[0];
+        assert_eq!(issue.name(), "invalid-exports");
+
+        // Targets are only checked for published packages, since they
+        // usually don't exist before building.
+        colored::control::set_override(false);
+        assert!(!issue.message().contains("doesn't exist"));
+
+        config.publish = Some(PublishConfig::default());
+        let issues = collect_issues(
+            &config,
+            collect_packages(Path::new("fixtures/exports")).unwrap(),
+        )
+        .into_iter()
+        .collect::<IndexMap<_, _>>();
+        let issue = issues
+            .get(&PackageType::Package(
+                "fixtures/exports/packages/invalid".to_string(),
+            ))
+            .unwrap()
+            .iter()
+            .find(|issue| issue.name() == "invalid-exports")
+            .unwrap();
+        assert!(issue.message().contains("doesn't exist"));
+    }
+
+    #[test]
+    fn collect_engines() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/engines")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "engines");
+        assert_eq!(packages_list.packages.len(), 6);
+
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 3);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+
+        for package in ["b", "d", "e"] {
+            assert_eq!(
+                issues
+                    .get(&PackageType::Package(format!(
+                        "fixtures/engines/packages/{}",
+                        package
+                    )))
+                    .unwrap()[0]
+                    .name(),
+                "incompatible-engines"
+            );
+        }
+    }
+
+    #[test]
+    fn collect_engines_exact() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/engines")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config: Config = args.into();
+        config.engines_strictness = EnginesStrictness::Exact;
+
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 4);
+    }
+
+    #[test]
+    fn collect_range_style() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/range-style")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "range-style");
+
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 3);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let web_issues = issues
+            .get(&PackageType::Package(
+                "fixtures/range-style/apps/web".to_string(),
+            ))
+            .unwrap();
+        let ui_issues = issues
+            .get(&PackageType::Package(
+                "fixtures/range-style/packages/ui".to_string(),
+            ))
+            .unwrap();
+
+        assert_eq!(web_issues.len(), 1);
+        assert_eq!(web_issues[0].name(), "dependency-range-style");
+        assert_eq!(
+            web_issues[0].why(),
+            "dependencies should use exact versions."
+        );
+        assert_eq!(ui_issues.len(), 2);
+        assert_eq!(ui_issues[0].why(), "dependencies should use caret ranges.");
+        assert_eq!(
+            ui_issues[1].why(),
+            "devDependencies should use exact versions."
+        );
+    }
+
+    #[test]
+    fn collect_range_style_ignored() {
+        let args = Args {
+            no_install: true,
+            ignore_dependency: vec!["react*".to_string()],
+            ..Args::for_path("fixtures/range-style")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let ui_issues = issues
+            .get(&PackageType::Package(
+                "fixtures/range-style/packages/ui".to_string(),
+            ))
+            .unwrap();
+        assert_eq!(
+            ui_issues[0].why(),
+            "devDependencies should use exact versions."
+        );
+    }
+
+    #[test]
+    fn collect_banned_dependencies() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/banned")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "banned");
+
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 3);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let abc_issues = issues
+            .get(&PackageType::Package(
+                "fixtures/banned/packages/abc".to_string(),
+            ))
+            .unwrap();
+        let def_issues = issues
+            .get(&PackageType::Package(
+                "fixtures/banned/packages/def".to_string(),
+            ))
+            .unwrap();
+
+        assert_eq!(abc_issues.len(), 2);
+        assert_eq!(abc_issues[0].name(), "banned-dependencies");
+        assert_eq!(abc_issues[0].why(), "Use date-fns instead of moment.");
+        assert_eq!(
+            abc_issues[1].why(),
+            "Dependency request isn't allowed in this package."
+        );
+        assert_eq!(def_issues.len(), 1);
+        assert_eq!(
+            def_issues[0].why(),
+            "Dependency @types/node isn't allowed in this package."
+        );
+    }
+
+    #[test]
+    fn collect_installed() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/installed")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "installed");
+        assert_eq!(packages_list.packages.len(), 3);
+
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 2);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+
+        for package in ["abc", "def"] {
+            assert_eq!(
+                issues
+                    .get(&PackageType::Package(format!(
+                        "fixtures/installed/packages/{}",
+                        package
+                    )))
+                    .unwrap()[0]
+                    .name(),
+                "installed-dependencies"
+            );
+        }
+    }
+
+    #[test]
+    fn collect_installed_ignored() {
+        let args = Args {
+            no_install: true,
+            ignore_dependency: vec!["react".to_string()],
+            ..Args::for_path("fixtures/installed")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues
+            .get(&PackageType::Package(
+                "fixtures/installed/packages/abc".to_string(),
+            ))
+            .unwrap()[0];
+
+        colored::control::set_override(false);
+        assert!(issue.message().contains(r#""dependencies": {"#));
+        assert!(!issue.message().contains("react"));
+    }
+
+    #[test]
+    fn collect_package_manager_mismatch() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/package-manager-mismatch")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 2);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        colored::control::set_override(false);
+
+        let root = &issues.get(&PackageType::Root).unwrap()[0];
+        assert_eq!(root.name(), "invalid-package-manager");
+        assert!(root
+            .message()
+            .contains("← but devEngines.packageManager requires yarn@>=4.0.0."));
+
+        let abc = &issues
+            .get(&PackageType::Package(
+                "fixtures/package-manager-mismatch/packages/abc".into(),
+            ))
+            .unwrap()[0];
+        assert_eq!(abc.name(), "invalid-package-manager");
+        assert!(abc
+            .message()
+            .contains("← but the root package.json uses pnpm@9.0.0."));
+    }
+
+    #[test]
+    fn collect_package_manager_lockfile() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/package-manager-lockfile")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues.get(&PackageType::Root).unwrap()[0];
+        assert_eq!(issue.name(), "invalid-package-manager");
+
+        colored::control::set_override(false);
+        assert!(issue.message().contains("← but the lockfile is yarn.lock."));
+    }
+
+    #[test]
+    fn collect_package_manager_bun_lockb() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/package-manager-bun-lockb")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues.get(&PackageType::Root).unwrap()[0];
+        assert_eq!(issue.name(), "invalid-package-manager");
+
+        colored::control::set_override(false);
+        assert!(issue.message().contains("← but the lockfile is bun.lockb."));
+    }
+
+    #[test]
+    fn collect_package_manager_invalid() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/package-manager-invalid")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        assert_eq!(
+            issues.get(&PackageType::Root).unwrap()[0].name(),
+            "invalid-package-manager"
+        );
+    }
+
+    #[test]
+    fn collect_key_order() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/key-order")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues
+            .get(&PackageType::Package(
+                "fixtures/key-order/packages/abc".into(),
+            ))
+            .unwrap()[0];
+        assert_eq!(issue.name(), "unordered-package-keys");
+
+        colored::control::set_override(false);
+        assert!(issue
+            .message()
+            .contains(r#""name": ...   ← should be before "version"."#));
+    }
+
+    #[test]
+    fn collect_key_order_custom() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/key-order-custom")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues
+            .get(&PackageType::Package(
+                "fixtures/key-order-custom/packages/abc".into(),
+            ))
+            .unwrap()[0];
+        assert_eq!(issue.name(), "unordered-package-keys");
+
+        colored::control::set_override(false);
+        assert!(issue
+            .message()
+            .contains(r#""private": ...   ← should be before "version"."#));
+    }
+
+    #[test]
+    fn collect_types_packages() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/types-packages")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config: Config = args.into();
+
+        // `node_modules` is only read with `checkInstalled`.
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 0);
+
+        config.check_installed = true;
+        let packages_list = collect_packages(Path::new("fixtures/types-packages")).unwrap();
+        let issues = collect_issues(&config, packages_list);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issues = issues
+            .get(&PackageType::Package(
+                "fixtures/types-packages/packages/abc".into(),
+            ))
+            .unwrap();
+        let issue = issues
+            .iter()
+            .find(|issue| issue.name() == "invalid-types-packages")
+            .unwrap();
+
+        colored::control::set_override(false);
+        let message = issue.message();
+        assert!(message.contains(r#""@types/react": "^17.0.0"   ← but react 18.2.0 is installed."#));
+        assert!(message
+            .contains(r#""@types/valibot": "^0.30.0"   ← valibot already ships its own types."#));
+        assert!(message.contains(r#""@types/zod": "^3.0.0"   ← zod already ships its own types."#));
+        assert!(!message.contains("@types/lodash"));
+        assert!(!message.contains("@types/babel__core"));
+    }
+
+    #[test]
+    fn collect_lockfile() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/lockfile")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "lockfile");
+        assert_eq!(packages_list.packages.len(), 3);
+
+        let config = args.into();
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 2);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+
+        for package in ["abc", "ghi"] {
+            assert_eq!(
+                issues
+                    .get(&PackageType::Package(format!(
+                        "fixtures/lockfile/packages/{}",
+                        package
+                    )))
+                    .unwrap()[0]
+                    .name(),
+                "outdated-lockfile"
+            );
+        }
+    }
+
+    #[test]
+    fn collect_resolved_versions() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/resolved-versions")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "resolved-versions");
+        assert_eq!(packages_list.packages.len(), 3);
+
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 3);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let mut issues = issues
+            .get(&PackageType::None)
+            .unwrap()
+            .iter()
+            .filter(|issue| issue.name() == "multiple-resolved-versions")
+            .map(|issue| issue.why())
+            .collect::<Vec<_>>();
+        issues.sort();
+
+        assert_eq!(
+            issues,
+            vec![
+                "Dependency loose-envify is resolved to multiple versions in pnpm-lock.yaml.",
+                "Dependency react is resolved to multiple versions in pnpm-lock.yaml.",
+            ]
+        );
+    }
+
+    #[test]
+    fn collect_resolved_versions_direct() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/resolved-versions")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        config.resolved_versions = Some(ResolvedVersions::Direct);
+
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 2);
+    }
+
+    #[test]
+    fn collect_unused_dependencies() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/unused")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "unused");
+        assert_eq!(packages_list.packages.len(), 2);
+
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues
+            .get(&PackageType::Package("fixtures/unused/packages/abc".into()))
+            .unwrap()[0];
+
+        assert_eq!(issue.name(), "unused-dependencies");
+
+        colored::control::set_override(false);
+        let message = issue.message();
+
+        for dependency in ["left-pad", "lodash", "zod"] {
+            assert!(message.contains(&format!(r#""{}""#, dependency)));
+        }
+
+        for dependency in [
+            "@scope/utils",
+            "@types/react",
+            "prisma",
+            "react",
+            "tailwindcss",
+        ] {
+            assert!(!message.contains(&format!(r#""{}""#, dependency)));
+        }
+    }
+
+    #[test]
+    fn collect_missing_dependencies() {
+        let args = Args {
+            no_install: true,
+            ..Args::for_path("fixtures/missing")
+        };
+
+        let packages_list = collect_packages(&args.path).unwrap();
+        assert_eq!(packages_list.root_package.get_name(), "missing");
+        assert_eq!(packages_list.packages.len(), 2);
+
+        let mut config = packages_list.root_package.get_config().unwrap();
+        config.merge(args);
+        let issues = collect_issues(&config, packages_list);
+        assert_eq!(issues.total_len(), 1);
+
+        let issues = issues.into_iter().collect::<IndexMap<_, _>>();
+        let issue = &issues
+            .get(&PackageType::Package(
+                "fixtures/missing/packages/abc".into(),
+            ))
+            .unwrap()[0];
+
+        assert_eq!(issue.name(), "missing-dependencies");
+
+        colored::control::set_override(false);
+        assert_eq!(
+            issue.message(),
+            r#"  │ {
+  │   "dependencies": {
+  +     "zod": "^3.22.0"   ← imported in src/index.ts.
+  +     "left-pad"   ← imported in src/index.ts.
+  +     "def": "workspace:*"   ← imported in src/index.ts.
+  │   }
+  │ }
+  │ {
+  │   "devDependencies": {
+  +     "vitest": "^1.2.0"   ← imported in src/index.test.ts.
+  +     "type-fest"   ← imported in src/index.ts.
+  │   }
+  │ }"#
+        );
+    }
 }
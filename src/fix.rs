@@ -0,0 +1,304 @@
+use crate::json;
+use anyhow::{anyhow, Result};
+use indexmap::IndexMap;
+use std::{
+    cell::{RefCell, RefMut},
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+/// A file edited by fixes, parsed once and serialized once.
+#[derive(Debug)]
+enum StagedFile {
+    Json {
+        value: serde_json::Value,
+        /// The contents before any edit, which unchanged values are kept from.
+        source: String,
+    },
+    Text(String),
+}
+
+/// The edits of every file, which all fixes contribute to. Files are read and
+/// parsed on their first edit, and only written once all fixes succeed, in a
+/// single pass.
+#[derive(Debug, Default)]
+pub struct EditPlan {
+    files: RefCell<IndexMap<PathBuf, StagedFile>>,
+}
+
+/// `./packages/abc/package.json` and `packages/abc/package.json` are the same file.
+fn normalize(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|component| *component != Component::CurDir)
+        .collect()
+}
+
+impl EditPlan {
+    /// The parsed JSON file, including the edits of previous fixes.
+    pub fn edit_json(&self, path: &Path) -> Result<RefMut<'_, serde_json::Value>> {
+        let mut files = self.files.borrow_mut();
+        let path = normalize(path);
+
+        if !matches!(files.get(&path), Some(StagedFile::Json { .. })) {
+            let source = match files.get(&path) {
+                Some(StagedFile::Text(contents)) => contents.clone(),
+                _ => fs::read_to_string(&path)?,
+            };
+            let value = serde_json::from_str(&source)?;
+
+            files.insert(path.clone(), StagedFile::Json { value, source });
+        }
+
+        Ok(RefMut::map(files, |files| match files.get_mut(&path) {
+            Some(StagedFile::Json { value, .. }) => value,
+            _ => unreachable!(),
+        }))
+    }
+
+    /// The contents of a file, including the edits of previous fixes.
+    pub fn read(&self, path: &Path) -> Result<String> {
+        match self.files.borrow().get(&normalize(path)) {
+            Some(StagedFile::Json { value, source }) => json::edit(source, value),
+            Some(StagedFile::Text(contents)) => Ok(contents.clone()),
+            None => Ok(fs::read_to_string(path)?),
+        }
+    }
+
+    pub fn write(&self, path: &Path, contents: String) {
+        self.files
+            .borrow_mut()
+            .insert(normalize(path), StagedFile::Text(contents));
+    }
+
+    /// Write all edited files, restoring the ones already written if any
+    /// write fails.
+    pub fn commit(self) -> Result<Transaction> {
+        let mut transaction = Transaction::default();
+
+        for (path, file) in self.files.into_inner() {
+            let result = file.contents().and_then(|contents| match contents {
+                Some(contents) => transaction.write(&path, contents.as_bytes()),
+                None => Ok(()),
+            });
+
+            if let Err(error) = result {
+                return Err(match transaction.rollback() {
+                    Ok(()) => anyhow!(
+                        "Failed to write {:?}, all files were restored: {}",
+                        path,
+                        error
+                    ),
+                    Err(rollback_error) => anyhow!(
+                        "Failed to write {:?}: {}. Failed to restore files: {}",
+                        path,
+                        error,
+                        rollback_error
+                    ),
+                });
+            }
+        }
+
+        Ok(transaction)
+    }
+}
+
+impl StagedFile {
+    /// The contents to write, or `None` if the file didn't change.
+    fn contents(&self) -> Result<Option<String>> {
+        match self {
+            StagedFile::Json { value, source } => {
+                let contents = json::edit(source, value)?;
+
+                Ok((&contents != source).then_some(contents))
+            }
+            StagedFile::Text(contents) => Ok(Some(contents.clone())),
+        }
+    }
+}
+
+/// Files changed by a commit, with their previous contents.
+#[derive(Debug, Default)]
+pub struct Transaction {
+    backups: Vec<(PathBuf, Option<Vec<u8>>)>,
+}
+
+impl Transaction {
+    /// Keep the current contents of a file that is about to be changed
+    /// outside of the transaction, e.g. a lockfile by the install command.
+    pub fn backup(&mut self, path: &Path) -> Result<()> {
+        let backup = match path.is_file() {
+            true => Some(fs::read(path)?),
+            false => None,
+        };
+
+        self.backups.push((path.to_path_buf(), backup));
+        Ok(())
+    }
+
+    fn write(&mut self, path: &Path, contents: &[u8]) -> Result<()> {
+        self.backup(path)?;
+
+        if let Err(error) = write_atomic(path, contents) {
+            self.backups.pop();
+            return Err(error);
+        }
+
+        Ok(())
+    }
+
+    /// Restore all changed files to their previous contents, in reverse order.
+    pub fn rollback(self) -> Result<()> {
+        let mut result = Ok(());
+
+        for (path, backup) in self.backups.into_iter().rev() {
+            let restored = match backup {
+                Some(contents) => write_atomic(&path, &contents),
+                None if path.exists() => fs::remove_file(&path).map_err(Into::into),
+                None => Ok(()),
+            };
+
+            if let Err(error) = restored {
+                result = result.and(Err(anyhow!("{:?}: {}", path, error)));
+            }
+        }
+
+        result
+    }
+}
+
+/// Write to a temporary file next to the target and rename it over the
+/// target, so the target is never left partially written.
+fn write_atomic(path: &Path, contents: &[u8]) -> Result<()> {
+    let file_name = path
+        .file_name()
+        .ok_or(anyhow!("Invalid file path {:?}", path))?;
+    let temp_path = path.with_file_name(format!(".{}.sherif", file_name.to_string_lossy()));
+
+    fs::write(&temp_path, contents)?;
+
+    if let Ok(metadata) = fs::metadata(path) {
+        fs::set_permissions(&temp_path, metadata.permissions())?;
+    }
+
+    if let Err(error) = fs::rename(&temp_path, path) {
+        let _ = fs::remove_file(&temp_path);
+        return Err(error.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn staged() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let path = directory.join("package.json");
+        fs::write(&path, "{}").unwrap();
+
+        let files = EditPlan::default();
+        assert_eq!(files.read(&path).unwrap(), "{}");
+
+        files.write(&path, r#"{"private":true}"#.into());
+        assert_eq!(files.read(&path).unwrap(), r#"{"private":true}"#);
+        assert_eq!(
+            files
+                .read(&directory.join(".").join("package.json"))
+                .unwrap(),
+            r#"{"private":true}"#
+        );
+
+        // Nothing is written until the commit.
+        assert_eq!(fs::read_to_string(&path).unwrap(), "{}");
+
+        files.commit().unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), r#"{"private":true}"#);
+    }
+
+    #[test]
+    fn edit_json() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let edited = directory.join("package.json");
+        let unchanged = directory.join("unchanged.json");
+        fs::write(&edited, "{\n    \"name\": \"abc\"\n}\n").unwrap();
+        fs::write(&unchanged, "{\"name\":\"def\"}").unwrap();
+
+        let files = EditPlan::default();
+        files.edit_json(&edited).unwrap()["private"] = serde_json::Value::Bool(true);
+        files
+            .edit_json(&directory.join(".").join("package.json"))
+            .unwrap()["version"] = serde_json::Value::String("1.0.0".into());
+        files.edit_json(&unchanged).unwrap();
+
+        // Edits of the same file are applied together, keeping its indentation.
+        assert_eq!(
+            files.read(&edited).unwrap(),
+            "{\n    \"name\": \"abc\",\n    \"private\": true,\n    \"version\": \"1.0.0\"\n}\n"
+        );
+        assert_eq!(
+            fs::read_to_string(&edited).unwrap(),
+            "{\n    \"name\": \"abc\"\n}\n"
+        );
+
+        // Files parsed without edits are read as they are.
+        assert_eq!(files.read(&unchanged).unwrap(), "{\"name\":\"def\"}");
+
+        files.commit().unwrap();
+        assert!(fs::read_to_string(&edited)
+            .unwrap()
+            .contains("\"version\": \"1.0.0\""));
+        // Files without edits are left untouched, even if formatted differently.
+        assert_eq!(
+            fs::read_to_string(&unchanged).unwrap(),
+            "{\"name\":\"def\"}"
+        );
+    }
+
+    #[test]
+    fn rollback() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let existing = directory.join("package.json");
+        let created = directory.join("packages/abc/package.json");
+        fs::write(&existing, "{}").unwrap();
+        fs::create_dir_all(created.parent().unwrap()).unwrap();
+
+        let files = EditPlan::default();
+        files.write(&existing, r#"{"private":true}"#.into());
+        files.write(&created, r#"{"name":"abc"}"#.into());
+
+        let transaction = files.commit().unwrap();
+        assert_eq!(
+            fs::read_to_string(&existing).unwrap(),
+            r#"{"private":true}"#
+        );
+        assert!(created.is_file());
+
+        transaction.rollback().unwrap();
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "{}");
+        assert!(!created.exists());
+    }
+
+    #[test]
+    fn commit_failure() {
+        let temp = tempfile::tempdir().unwrap();
+        let directory = temp.path();
+        let existing = directory.join("package.json");
+        fs::write(&existing, "{}").unwrap();
+
+        let files = EditPlan::default();
+        files.write(&existing, r#"{"private":true}"#.into());
+        files.write(
+            &directory.join("missing/package.json"),
+            r#"{"name":"missing"}"#.into(),
+        );
+
+        assert!(files.commit().is_err());
+        assert_eq!(fs::read_to_string(&existing).unwrap(), "{}");
+        assert!(!directory.join(".package.json.sherif").exists());
+    }
+}
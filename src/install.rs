@@ -2,7 +2,7 @@ use crate::printer::get_render_config;
 use anyhow::{anyhow, Result};
 use colored::Colorize;
 use inquire::Select;
-use std::{fmt::Display, fs, process::Command, process::Stdio};
+use std::{fmt::Display, fs, path::Path, process::Command, process::Stdio};
 
 const PACKAGE_MANAGERS: [&str; 4] = ["npm", "yarn", "pnpm", "bun"];
 
@@ -15,17 +15,25 @@ enum PackageManager {
 }
 
 impl PackageManager {
-    pub fn resolve() -> Result<Self> {
-        if fs::metadata("package-lock.json").is_ok() {
+    pub fn resolve(root: &Path, interactive: bool) -> Result<Self> {
+        if fs::metadata(root.join("package-lock.json")).is_ok() {
             return Ok(PackageManager::Npm);
-        } else if fs::metadata("bun.lockb").is_ok() || fs::metadata("bun.lock").is_ok() {
+        } else if fs::metadata(root.join("bun.lockb")).is_ok()
+            || fs::metadata(root.join("bun.lock")).is_ok()
+        {
             return Ok(PackageManager::Bun);
-        } else if fs::metadata("yarn.lock").is_ok() {
+        } else if fs::metadata(root.join("yarn.lock")).is_ok() {
             return Ok(PackageManager::Yarn);
-        } else if fs::metadata("pnpm-lock.yaml").is_ok() {
+        } else if fs::metadata(root.join("pnpm-lock.yaml")).is_ok() {
             return Ok(PackageManager::Pnpm);
         }
 
+        if !interactive {
+            return Err(anyhow!(
+                "No lockfile found to detect the package manager, and can't prompt to select one in non-interactive mode. Use `--no-install` to skip the install command"
+            ));
+        }
+
         let package_manager =
             Select::new("Select a package manager to use", PACKAGE_MANAGERS.to_vec())
                 .with_render_config(get_render_config())
@@ -55,8 +63,8 @@ impl Display for PackageManager {
     }
 }
 
-pub fn install() -> Result<()> {
-    let package_manager = PackageManager::resolve()?;
+pub fn install(root: &Path, interactive: bool) -> Result<()> {
+    let package_manager = PackageManager::resolve(root, interactive)?;
 
     println!(
         " {}",
@@ -66,6 +74,7 @@ pub fn install() -> Result<()> {
 
     let mut command = Command::new(package_manager.to_string())
         .arg("install")
+        .current_dir(root)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
         .spawn()?;
@@ -81,32 +90,32 @@ pub fn install() -> Result<()> {
 
 #[cfg(test)]
 mod test {
+    use super::*;
     use crate::collect::collect_packages;
     use serde_json::Value;
-    use std::{fs, path::Path};
 
     #[test]
     fn test_detect_package_manager() {
-        use super::*;
-        use std::fs;
-
-        fs::File::create("package-lock.json").unwrap();
-        assert_eq!(PackageManager::resolve().unwrap(), PackageManager::Npm);
-        fs::remove_file("package-lock.json").unwrap();
-
-        fs::File::create("bun.lockb").unwrap();
-        assert_eq!(PackageManager::resolve().unwrap(), PackageManager::Bun);
-        fs::remove_file("bun.lockb").unwrap();
-
-        fs::File::create("bun.lock").unwrap();
-        assert_eq!(PackageManager::resolve().unwrap(), PackageManager::Bun);
-        fs::remove_file("bun.lock").unwrap();
-
-        fs::File::create("yarn.lock").unwrap();
-        assert_eq!(PackageManager::resolve().unwrap(), PackageManager::Yarn);
-        fs::remove_file("yarn.lock").unwrap();
+        let directory = tempfile::tempdir().unwrap();
+        let root = directory.path();
+
+        for (lockfile, package_manager) in [
+            ("package-lock.json", PackageManager::Npm),
+            ("bun.lockb", PackageManager::Bun),
+            ("bun.lock", PackageManager::Bun),
+            ("yarn.lock", PackageManager::Yarn),
+            ("pnpm-lock.yaml", PackageManager::Pnpm),
+        ] {
+            fs::File::create(root.join(lockfile)).unwrap();
+            assert_eq!(
+                PackageManager::resolve(root, false).unwrap(),
+                package_manager
+            );
+            fs::remove_file(root.join(lockfile)).unwrap();
+        }
 
-        assert_eq!(PackageManager::resolve().unwrap(), PackageManager::Pnpm);
+        // Without a lockfile, the package manager can only be selected by prompting.
+        assert!(PackageManager::resolve(root, false).is_err());
     }
 
     #[test]
@@ -114,14 +123,11 @@ mod test {
         let root = Path::new("fixtures/install");
         let _ = collect_packages(root);
 
-        std::env::set_current_dir("fixtures/install").unwrap();
-        super::install().unwrap();
+        super::install(root, false).unwrap();
 
         // Test if the previously empty package-lock.json now contains the "install" name to indicate that the install command was run
-        let file = fs::File::open("package-lock.json");
+        let file = fs::File::open(root.join("package-lock.json"));
         let json: Result<Value, serde_json::Error> = serde_json::from_reader(file.unwrap());
         assert_eq!(json.unwrap()["name"], "install");
-
-        std::env::set_current_dir("../../").unwrap();
     }
 }
@@ -1,35 +1,444 @@
-use anyhow::Result;
-use detect_indent::{detect_indent, Indent};
+use anyhow::{anyhow, Result};
+use detect_indent::detect_indent;
 use detect_newline_style::LineEnding;
-use serde::{Deserialize, Serialize};
-use serde_json::ser::PrettyFormatter;
-
-pub fn deserialize<'a, T>(value: &'a str) -> Result<(T, Indent, LineEnding)>
-where
-    T: Deserialize<'a>,
-{
-    let json = serde_json::from_str::<T>(value)?;
-    let indent = detect_indent(value);
-    let lineending = LineEnding::find_or_use_lf(value);
-
-    Ok((json, indent, lineending))
-}
-
-pub fn serialize<T>(value: &T, indent: Indent, lineending: LineEnding) -> Result<String>
-where
-    T: Serialize,
-{
-    let mut buf = Vec::new();
-    let formatter = PrettyFormatter::with_indent(indent.indent().as_bytes());
-    let mut serializer = serde_json::Serializer::with_formatter(&mut buf, formatter);
-
-    value.serialize(&mut serializer)?;
-    let mut json = String::from_utf8(buf)?;
-    json += match lineending {
-        LineEnding::CR => "\r",
-        LineEnding::LF => "\n",
-        LineEnding::CRLF => "\r\n",
+use serde::Serialize;
+use serde_json::{ser::PrettyFormatter, Value};
+use std::ops::Range;
+
+/// A JSON value with the byte ranges of its source, so unchanged values can
+/// be written back as-is.
+#[derive(Debug)]
+enum Node {
+    Object {
+        span: Range<usize>,
+        members: Vec<Member>,
+    },
+    Array {
+        span: Range<usize>,
+        elements: Vec<Node>,
+    },
+    Scalar {
+        span: Range<usize>,
+        value: Value,
+    },
+}
+
+#[derive(Debug)]
+struct Member {
+    key: String,
+    /// Where the key starts, so `"key": ` is kept with its original spacing.
+    start: usize,
+    value: Node,
+}
+
+impl Node {
+    fn span(&self) -> &Range<usize> {
+        match self {
+            Node::Object { span, .. } | Node::Array { span, .. } | Node::Scalar { span, .. } => {
+                span
+            }
+        }
+    }
+
+    /// Whether the value is the same as the source, including the order of keys.
+    fn is_unchanged(&self, value: &Value) -> bool {
+        match (self, value) {
+            (Node::Object { members, .. }, Value::Object(object)) => {
+                members.len() == object.len()
+                    && members.iter().zip(object).all(|(member, (key, value))| {
+                        member.key == *key && member.value.is_unchanged(value)
+                    })
+            }
+            (Node::Array { elements, .. }, Value::Array(array)) => {
+                elements.len() == array.len()
+                    && elements
+                        .iter()
+                        .zip(array)
+                        .all(|(element, value)| element.is_unchanged(value))
+            }
+            (Node::Scalar { value: source, .. }, value) => source == value,
+            _ => false,
+        }
+    }
+}
+
+struct Parser<'a> {
+    source: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn skip_whitespace(&mut self) {
+        let bytes = self.source.as_bytes();
+
+        while self.position < bytes.len() && bytes[self.position].is_ascii_whitespace() {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.source.as_bytes().get(self.position).copied()
+    }
+
+    fn expect(&mut self, expected: u8) -> Result<()> {
+        match self.peek() {
+            Some(byte) if byte == expected => {
+                self.position += 1;
+                Ok(())
+            }
+            _ => Err(anyhow!(
+                "Expected `{}` at position {}",
+                expected as char,
+                self.position
+            )),
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Node> {
+        match self.peek() {
+            Some(b'{') => self.parse_object(),
+            Some(b'[') => self.parse_array(),
+            Some(_) => self.parse_scalar(),
+            None => Err(anyhow!("Unexpected end of JSON")),
+        }
+    }
+
+    fn parse_object(&mut self) -> Result<Node> {
+        let start = self.position;
+        let mut members = Vec::new();
+        self.expect(b'{')?;
+
+        if self.peek() != Some(b'}') {
+            loop {
+                self.skip_whitespace();
+                let key_start = self.position;
+                let key = match self.parse_scalar()? {
+                    Node::Scalar {
+                        value: Value::String(key),
+                        ..
+                    } => key,
+                    _ => return Err(anyhow!("Expected a key at position {}", key_start)),
+                };
+
+                self.expect(b':')?;
+                let value = self.parse_value()?;
+
+                members.push(Member {
+                    key,
+                    start: key_start,
+                    value,
+                });
+
+                if self.peek() != Some(b',') {
+                    break;
+                }
+
+                self.position += 1;
+            }
+        }
+
+        self.expect(b'}')?;
+
+        Ok(Node::Object {
+            span: start..self.position,
+            members,
+        })
+    }
+
+    fn parse_array(&mut self) -> Result<Node> {
+        let start = self.position;
+        let mut elements = Vec::new();
+        self.expect(b'[')?;
+
+        if self.peek() != Some(b']') {
+            loop {
+                elements.push(self.parse_value()?);
+
+                if self.peek() != Some(b',') {
+                    break;
+                }
+
+                self.position += 1;
+            }
+        }
+
+        self.expect(b']')?;
+
+        Ok(Node::Array {
+            span: start..self.position,
+            elements,
+        })
+    }
+
+    fn parse_scalar(&mut self) -> Result<Node> {
+        let bytes = self.source.as_bytes();
+        let start = self.position;
+
+        if bytes.get(start) == Some(&b'"') {
+            self.position += 1;
+
+            while self.position < bytes.len() && bytes[self.position] != b'"' {
+                if bytes[self.position] == b'\\' {
+                    self.position += 1;
+                }
+
+                self.position += 1;
+            }
+
+            self.position += 1;
+        } else {
+            while self.position < bytes.len()
+                && !matches!(bytes[self.position], b',' | b'}' | b']' | b':')
+                && !bytes[self.position].is_ascii_whitespace()
+            {
+                self.position += 1;
+            }
+        }
+
+        let span = start..self.position.min(bytes.len());
+        let value = serde_json::from_str(&self.source[span.clone()])?;
+
+        Ok(Node::Scalar { span, value })
+    }
+}
+
+/// Writes the edited value using the source of unchanged values, and the
+/// formatting of the surrounding source for changed ones.
+struct Printer<'a> {
+    source: &'a str,
+    indent: String,
+    lineending: &'static str,
+}
+
+impl Printer<'_> {
+    /// A value that isn't in the source, indented at the given depth.
+    fn fresh(&self, value: &Value, depth: usize) -> Result<String> {
+        let mut buf = Vec::new();
+        let formatter = PrettyFormatter::with_indent(self.indent.as_bytes());
+        let mut serializer = serde_json::Serializer::with_formatter(&mut buf, formatter);
+
+        value.serialize(&mut serializer)?;
+
+        let newline = format!("{}{}", self.lineending, self.indent.repeat(depth));
+        Ok(String::from_utf8(buf)?.replace('\n', &newline))
+    }
+
+    /// The separator between items, e.g. `,\n    ` or `, `.
+    fn separator(&self, open: usize, spans: &[Range<usize>]) -> String {
+        match spans {
+            [first, second, ..] => self.source[first.end..second.start].to_string(),
+            [first, ..] => match &self.source[open + 1..first.start] {
+                "" => ", ".to_string(),
+                whitespace => format!(",{}", whitespace),
+            },
+            [] => String::new(),
+        }
+    }
+
+    /// Join items with the separator and the outer whitespace of the source.
+    fn join(&self, span: &Range<usize>, spans: &[Range<usize>], items: Vec<String>) -> String {
+        let open = &self.source[span.start..span.start + 1];
+        let close = &self.source[span.end - 1..span.end];
+        let (first, last) = (spans.first().unwrap(), spans.last().unwrap());
+
+        format!(
+            "{}{}{}{}{}",
+            open,
+            &self.source[span.start + 1..first.start],
+            items.join(&self.separator(span.start, spans)),
+            &self.source[last.end..span.end - 1],
+            close,
+        )
+    }
+
+    fn print(&self, node: &Node, value: &Value, depth: usize) -> Result<String> {
+        if node.is_unchanged(value) {
+            return Ok(self.source[node.span().clone()].to_string());
+        }
+
+        match (node, value) {
+            (Node::Object { span, members }, Value::Object(object))
+                if !members.is_empty() && !object.is_empty() =>
+            {
+                let items = object
+                    .iter()
+                    .map(
+                        |(key, value)| match members.iter().find(|member| member.key == *key) {
+                            Some(member) => Ok(format!(
+                                "{}{}",
+                                &self.source[member.start..member.value.span().start],
+                                self.print(&member.value, value, depth + 1)?
+                            )),
+                            None => Ok(format!(
+                                "{}: {}",
+                                serde_json::to_string(key)?,
+                                self.fresh(value, depth + 1)?
+                            )),
+                        },
+                    )
+                    .collect::<Result<Vec<_>>>()?;
+                let spans = members
+                    .iter()
+                    .map(|member| member.start..member.value.span().end)
+                    .collect::<Vec<_>>();
+
+                Ok(self.join(span, &spans, items))
+            }
+            (Node::Array { span, elements }, Value::Array(array))
+                if !elements.is_empty() && !array.is_empty() =>
+            {
+                let mut used = vec![false; elements.len()];
+                let items = array
+                    .iter()
+                    .map(|value| {
+                        // Keep the source of elements that are still there, even if moved.
+                        let index = (0..elements.len())
+                            .find(|&index| !used[index] && elements[index].is_unchanged(value));
+
+                        match index {
+                            Some(index) => {
+                                used[index] = true;
+                                Ok(self.source[elements[index].span().clone()].to_string())
+                            }
+                            None => self.fresh(value, depth + 1),
+                        }
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let spans = elements
+                    .iter()
+                    .map(|element| element.span().clone())
+                    .collect::<Vec<_>>();
+
+                Ok(self.join(span, &spans, items))
+            }
+            _ => self.fresh(value, depth),
+        }
+    }
+}
+
+/// Write `value` as an edit of `source`: unchanged values keep their exact
+/// bytes, and changed ones follow the indentation and line endings of the file.
+pub fn edit(source: &str, value: &Value) -> Result<String> {
+    let mut parser = Parser {
+        source,
+        position: 0,
     };
+    let root = parser.parse_value()?;
+
+    if parser.peek().is_some() {
+        return Err(anyhow!(
+            "Unexpected trailing characters at position {}",
+            parser.position
+        ));
+    }
+
+    let printer = Printer {
+        source,
+        indent: detect_indent(source).indent().to_string(),
+        lineending: match LineEnding::find_or_use_lf(source) {
+            LineEnding::CR => "\r",
+            LineEnding::LF => "\n",
+            LineEnding::CRLF => "\r\n",
+        },
+    };
+    let span = root.span();
+
+    Ok(format!(
+        "{}{}{}",
+        &source[..span.start],
+        printer.print(&root, value, 0)?,
+        &source[span.end..]
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn edit_with(source: &str, edit_value: impl FnOnce(&mut Value)) -> String {
+        let mut value = serde_json::from_str::<Value>(source).unwrap();
+        edit_value(&mut value);
+        edit(source, &value).unwrap()
+    }
+
+    #[test]
+    fn unchanged() {
+        let source = "{\n  \"name\": \"\\u00e9\",\n  \"version\": 1.0e0,\n  \"files\": [\"dist\"],\n  \"dependencies\": { },\n  \"scripts\": {\"build\":\"tsc\"}\n}";
+
+        assert_eq!(edit_with(source, |_| {}), source);
+    }
+
+    #[test]
+    fn replace_value() {
+        let source = "{\n  \"name\": \"\\u00e9\",\n  \"files\": [\"dist\"],\n  \"dependencies\": {\n    \"react\": \"18.2.0\"\n  }\n}\n";
+
+        assert_eq!(
+            edit_with(source, |value| {
+                value["dependencies"]["react"] = Value::String("18.3.1".into())
+            }),
+            "{\n  \"name\": \"\\u00e9\",\n  \"files\": [\"dist\"],\n  \"dependencies\": {\n    \"react\": \"18.3.1\"\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let source = "{\r\n    \"name\": \"abc\",\r\n    \"dependencies\": {}\r\n}\r\n";
+
+        assert_eq!(
+            edit_with(source, |value| {
+                let object = value.as_object_mut().unwrap();
+                object.retain(|key, _| key != "dependencies");
+                object.insert("private".into(), Value::Bool(true));
+                object.insert(
+                    "engines".into(),
+                    serde_json::json!({ "node": ">=18" }),
+                );
+            }),
+            "{\r\n    \"name\": \"abc\",\r\n    \"private\": true,\r\n    \"engines\": {\r\n        \"node\": \">=18\"\r\n    }\r\n}\r\n"
+        );
+    }
+
+    #[test]
+    fn reorder() {
+        let source =
+            "{\n\t\"version\": \"1.0.0\",\n\t\"name\": \"abc\",\n\t\"files\": [\"dist\"]\n}\n";
+
+        assert_eq!(
+            edit_with(source, |value| {
+                let object = value.as_object_mut().unwrap();
+                let mut entries = std::mem::take(object).into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                object.extend(entries);
+            }),
+            "{\n\t\"files\": [\"dist\"],\n\t\"name\": \"abc\",\n\t\"version\": \"1.0.0\"\n}\n"
+        );
+    }
+
+    #[test]
+    fn compact_array() {
+        let source = "{\n  \"workspaces\": [\"packages/*\"]\n}\n";
+
+        assert_eq!(
+            edit_with(source, |value| {
+                value["workspaces"]
+                    .as_array_mut()
+                    .unwrap()
+                    .push(Value::String("apps/*".into()));
+            }),
+            "{\n  \"workspaces\": [\"packages/*\", \"apps/*\"]\n}\n"
+        );
+        assert_eq!(
+            edit_with(source, |value| {
+                value["workspaces"] = serde_json::json!(["apps/*", "packages/*"]);
+            }),
+            "{\n  \"workspaces\": [\"apps/*\", \"packages/*\"]\n}\n"
+        );
+    }
 
-    Ok(json)
+    #[test]
+    fn invalid() {
+        assert!(edit("{\"name\": }", &Value::Null).is_err());
+        assert!(edit("{} {}", &Value::Null).is_err());
+    }
 }
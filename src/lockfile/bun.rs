@@ -0,0 +1,299 @@
+use super::{merge_specifiers, LockedPackage, Lockfile, DEPENDENCIES_FIELDS};
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+/// Parse a text `bun.lock` file, which records each workspace package under its
+/// path in `workspaces` (`""` for the root package). The binary `bun.lockb`
+/// format isn't supported.
+///
+/// Packages are keyed by their install path, e.g. `react` when hoisted and
+/// `abc/react` when nested under the `abc` workspace or package.
+pub fn parse(value: &str) -> Result<Lockfile> {
+    let value = serde_json::from_str::<Value>(&strip_trailing_commas(value))?;
+    let empty = Map::new();
+    let entries = value
+        .get("packages")
+        .and_then(|value| value.as_object())
+        .unwrap_or(&empty);
+
+    let mut importers = IndexMap::new();
+    let mut resolved_importers = IndexMap::new();
+
+    if let Some(workspaces) = value.get("workspaces").and_then(|value| value.as_object()) {
+        for (path, workspace) in workspaces {
+            let (path, base) = match path.as_str() {
+                "" => (".".to_string(), ""),
+                path => (
+                    path.to_string(),
+                    workspace
+                        .get("name")
+                        .and_then(|name| name.as_str())
+                        .unwrap_or(path),
+                ),
+            };
+
+            resolved_importers.insert(
+                path.clone(),
+                resolve_dependencies(entries, base, workspace, &DEPENDENCIES_FIELDS),
+            );
+            importers.insert(path, merge_specifiers(workspace, &DEPENDENCIES_FIELDS));
+        }
+    }
+
+    let mut packages = IndexMap::new();
+
+    for (key, entry) in entries {
+        let Some(entry) = entry.as_array() else {
+            continue;
+        };
+
+        let Some((name, version)) =
+            entry
+                .first()
+                .and_then(|ident| ident.as_str())
+                .and_then(|ident| {
+                    let index = ident.get(1..)?.find('@')? + 1;
+                    Some((&ident[..index], &ident[index + 1..]))
+                })
+        else {
+            continue;
+        };
+
+        // Workspace, linked and local packages use a `protocol:` version.
+        if version.contains(':') {
+            continue;
+        }
+
+        let info = entry
+            .iter()
+            .find(|value| value.is_object())
+            .cloned()
+            .unwrap_or_default();
+
+        packages.insert(
+            key.clone(),
+            LockedPackage {
+                name: name.to_string(),
+                version: version.to_string(),
+                dependencies: resolve_dependencies(
+                    entries,
+                    key,
+                    &info,
+                    &["dependencies", "optionalDependencies", "peerDependencies"],
+                ),
+            },
+        );
+    }
+
+    Ok(Lockfile {
+        importers: Some(importers),
+        packages,
+        resolved_importers,
+        ..Default::default()
+    })
+}
+
+/// Resolve dependencies by looking for the closest package containing them,
+/// from the given key up to the hoisted ones.
+fn resolve_dependencies(
+    entries: &Map<String, Value>,
+    base: &str,
+    value: &Value,
+    fields: &[&str],
+) -> Vec<String> {
+    let mut keys = Vec::new();
+
+    for field in fields {
+        let Some(dependencies) = value.get(field).and_then(|value| value.as_object()) else {
+            continue;
+        };
+
+        for name in dependencies.keys() {
+            let mut base = base.to_string();
+
+            loop {
+                let key = match base.as_str() {
+                    "" => name.clone(),
+                    base => format!("{}/{}", base, name),
+                };
+
+                if entries.contains_key(&key) {
+                    keys.push(key);
+                    break;
+                }
+
+                if base.is_empty() {
+                    break;
+                }
+
+                base = parent(&base);
+            }
+        }
+    }
+
+    keys
+}
+
+/// Remove the last package name from a key, which can be scoped.
+fn parent(key: &str) -> String {
+    let mut names = Vec::<String>::new();
+
+    for segment in key.split('/') {
+        match names.last_mut() {
+            Some(name) if name.starts_with('@') && !name.contains('/') => {
+                name.push('/');
+                name.push_str(segment);
+            }
+            _ => names.push(segment.to_string()),
+        }
+    }
+
+    names.pop();
+    names.join("/")
+}
+
+/// `bun.lock` is JSONC and allows trailing commas, which serde_json doesn't.
+fn strip_trailing_commas(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(char) = chars.next() {
+        if in_string {
+            result.push(char);
+
+            match char {
+                '\\' => {
+                    if let Some(escaped) = chars.next() {
+                        result.push(escaped);
+                    }
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+
+            continue;
+        }
+
+        match char {
+            '"' => {
+                in_string = true;
+                result.push(char);
+            }
+            ',' => {
+                let next = chars.clone().find(|char| !char.is_whitespace());
+
+                if !matches!(next, Some('}') | Some(']')) {
+                    result.push(char);
+                }
+            }
+            _ => result.push(char),
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_lockfile() {
+        let lockfile = parse(
+            r#"{
+  "lockfileVersion": 1,
+  "workspaces": {
+    "": {
+      "name": "root",
+      "devDependencies": {
+        "typescript": "^5.3.0",
+      },
+    },
+    "packages/abc": {
+      "name": "abc",
+      "dependencies": {
+        "react": "^18.2.0",
+      },
+    },
+  },
+  "packages": {
+    "react": ["react@18.2.0", "", {}, "sha512-,}"],
+  }
+}"#,
+        )
+        .unwrap();
+
+        let importers = lockfile.importers.unwrap();
+        assert_eq!(importers["."]["typescript"], "^5.3.0");
+        assert_eq!(importers["packages/abc"]["react"], "^18.2.0");
+    }
+
+    #[test]
+    fn resolved() {
+        let lockfile = parse(
+            r#"{
+  "lockfileVersion": 1,
+  "workspaces": {
+    "": {
+      "name": "root",
+      "dependencies": {
+        "react": "^18.2.0",
+      },
+    },
+    "packages/abc": {
+      "name": "abc",
+      "dependencies": {
+        "def": "workspace:*",
+        "react": "^17.0.0",
+      },
+    },
+  },
+  "packages": {
+    "@scope/pkg": ["@scope/pkg@1.0.0", "", { "dependencies": { "loose-envify": "^1.1.0" } }, "sha512-"],
+    "@scope/pkg/loose-envify": ["loose-envify@1.3.0", "", {}, "sha512-"],
+    "abc/react": ["react@17.0.2", "", { "dependencies": { "loose-envify": "^1.1.0" } }, "sha512-"],
+    "def": ["def@workspace:packages/def"],
+    "loose-envify": ["loose-envify@1.4.0", "", {}, "sha512-"],
+    "react": ["react@18.2.0", "", { "dependencies": { "loose-envify": "^1.1.0" } }, "sha512-"],
+  }
+}"#,
+        )
+        .unwrap();
+
+        assert_eq!(lockfile.resolved_importers["."], vec!["react"]);
+        assert_eq!(
+            lockfile.resolved_importers["packages/abc"],
+            vec!["def", "abc/react"]
+        );
+        assert_eq!(
+            lockfile.packages["abc/react"],
+            LockedPackage {
+                name: "react".into(),
+                version: "17.0.2".into(),
+                dependencies: vec!["loose-envify".into()],
+            }
+        );
+        assert_eq!(
+            lockfile.packages["@scope/pkg"].dependencies,
+            vec!["@scope/pkg/loose-envify"]
+        );
+        assert!(!lockfile.packages.contains_key("def"));
+    }
+
+    #[test]
+    fn parent_key() {
+        assert_eq!(parent("abc/react"), "abc");
+        assert_eq!(parent("@scope/pkg/@types/react"), "@scope/pkg");
+        assert_eq!(parent("@scope/pkg"), "");
+    }
+
+    #[test]
+    fn trailing_commas() {
+        assert_eq!(
+            strip_trailing_commas(r#"{ "a": [1, 2,], "b": ",}", }"#),
+            r#"{ "a": [1, 2], "b": ",}" }"#
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use anyhow::{anyhow, Result};
+use indexmap::{IndexMap, IndexSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Display,
+    fs,
+    path::Path,
+};
+
+mod bun;
+mod npm;
+mod pnpm;
+mod yarn;
+
+/// Dependency names and their specifiers, e.g. `react` => `^18.2.0`.
+pub type Specifiers = IndexMap<String, String>;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LockfileKind {
+    Npm,
+    Bun,
+    /// The binary lockfile of Bun before v1.2, which is only detected, not parsed.
+    BunLockb,
+    Yarn,
+    Pnpm,
+}
+
+impl LockfileKind {
+    /// In the same order as the package manager detection used by the install command.
+    pub const ALL: [LockfileKind; 5] = [
+        LockfileKind::Npm,
+        LockfileKind::Bun,
+        LockfileKind::BunLockb,
+        LockfileKind::Yarn,
+        LockfileKind::Pnpm,
+    ];
+
+    pub fn package_manager(&self) -> &'static str {
+        match self {
+            LockfileKind::Npm => "npm",
+            LockfileKind::Bun | LockfileKind::BunLockb => "bun",
+            LockfileKind::Yarn => "yarn",
+            LockfileKind::Pnpm => "pnpm",
+        }
+    }
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            LockfileKind::Npm => "package-lock.json",
+            LockfileKind::Bun => "bun.lock",
+            LockfileKind::BunLockb => "bun.lockb",
+            LockfileKind::Yarn => "yarn.lock",
+            LockfileKind::Pnpm => "pnpm-lock.yaml",
+        }
+    }
+}
+
+impl Display for LockfileKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file_name())
+    }
+}
+
+/// A package resolved in the lockfile.
+#[derive(Debug, PartialEq)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    /// Keys of the packages this package depends on.
+    pub dependencies: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+pub struct Lockfile {
+    /// Dependencies declared by each workspace package when the lockfile was
+    /// generated, by path relative to the root (`.` for the root package).
+    pub importers: Option<IndexMap<String, Specifiers>>,
+    /// Resolved descriptors (e.g. `react@^18.2.0`) and the key of the package
+    /// they resolve to, for lockfiles that don't record importers (Yarn classic).
+    pub descriptors: Option<HashMap<String, String>>,
+    /// Packages resolved in the lockfile, by a key unique to each lockfile format.
+    pub packages: IndexMap<String, LockedPackage>,
+    /// Keys of the packages each workspace package directly depends on.
+    pub resolved_importers: IndexMap<String, Vec<String>>,
+}
+
+impl Lockfile {
+    /// Find the lockfile at the root of the monorepo.
+    pub fn find(root: &Path) -> Option<LockfileKind> {
+        LockfileKind::ALL
+            .into_iter()
+            .find(|kind| root.join(kind.file_name()).is_file())
+    }
+
+    pub fn parse(kind: LockfileKind, value: &str) -> Result<Self> {
+        match kind {
+            LockfileKind::Npm => npm::parse(value),
+            LockfileKind::Bun => bun::parse(value),
+            LockfileKind::Yarn => yarn::parse(value),
+            LockfileKind::Pnpm => pnpm::parse(value),
+            LockfileKind::BunLockb => Err(anyhow!("The binary bun.lockb format isn't supported")),
+        }
+    }
+
+    pub fn read(root: &Path) -> Option<(LockfileKind, Self)> {
+        let kind = Self::find(root)?;
+        let value = fs::read_to_string(root.join(kind.file_name())).ok()?;
+
+        // An unparsable lockfile is left to the package manager to report.
+        Self::parse(kind, &value)
+            .ok()
+            .map(|lockfile| (kind, lockfile))
+    }
+
+    /// Compare the dependencies declared by a package with the ones recorded in
+    /// the lockfile, returning the name, declared specifier and locked specifier
+    /// of each outdated dependency.
+    pub fn outdated(
+        &self,
+        importer: &str,
+        declared: &Specifiers,
+        workspace_packages: &HashSet<String>,
+    ) -> Vec<(String, Option<String>, Option<String>)> {
+        let mut outdated = Vec::new();
+
+        match (&self.importers, &self.descriptors) {
+            (Some(importers), _) => {
+                let empty = Specifiers::new();
+                let locked = importers.get(importer).unwrap_or(&empty);
+
+                for (name, specifier) in declared {
+                    match locked.get(name) {
+                        Some(locked_specifier) if locked_specifier == specifier => {}
+                        locked_specifier => outdated.push((
+                            name.clone(),
+                            Some(specifier.clone()),
+                            locked_specifier.cloned(),
+                        )),
+                    }
+                }
+
+                for (name, locked_specifier) in locked {
+                    if !declared.contains_key(name) {
+                        outdated.push((name.clone(), None, Some(locked_specifier.clone())));
+                    }
+                }
+            }
+            (None, Some(descriptors)) => {
+                for (name, specifier) in declared {
+                    // Workspace packages and local paths aren't recorded in the lockfile.
+                    if workspace_packages.contains(name) || specifier.contains(':') {
+                        continue;
+                    }
+
+                    if !descriptors.contains_key(&format!("{}@{}", name, specifier)) {
+                        outdated.push((name.clone(), Some(specifier.clone()), None));
+                    }
+                }
+            }
+            // Nothing to compare against, e.g. npm lockfile v1.
+            (None, None) => {}
+        }
+
+        outdated
+    }
+
+    /// The name and version of each package a workspace package resolves to,
+    /// either only its direct dependencies or all of them transitively.
+    pub fn resolved(
+        &self,
+        importer: &str,
+        declared: &Specifiers,
+        transitive: bool,
+    ) -> IndexSet<(&str, &str)> {
+        let mut keys = match (self.resolved_importers.get(importer), &self.descriptors) {
+            (Some(keys), _) => keys.iter().map(String::as_str).collect::<Vec<_>>(),
+            (None, Some(descriptors)) => declared
+                .iter()
+                .filter_map(|(name, specifier)| {
+                    descriptors
+                        .get(&format!("{}@{}", name, specifier))
+                        .map(String::as_str)
+                })
+                .collect(),
+            (None, None) => Vec::new(),
+        };
+
+        let mut visited = HashSet::new();
+        let mut resolved = IndexSet::new();
+
+        while let Some(key) = keys.pop() {
+            if !visited.insert(key) {
+                continue;
+            }
+
+            if let Some(package) = self.packages.get(key) {
+                resolved.insert((package.name.as_str(), package.version.as_str()));
+
+                if transitive {
+                    keys.extend(package.dependencies.iter().map(String::as_str));
+                }
+            }
+        }
+
+        resolved
+    }
+}
+
+/// Merge the dependencies fields of a `package.json`-like object into a single map.
+fn merge_specifiers(value: &serde_json::Value, fields: &[&str]) -> Specifiers {
+    let mut specifiers = Specifiers::new();
+
+    for field in fields {
+        if let Some(dependencies) = value.get(field).and_then(|value| value.as_object()) {
+            for (name, specifier) in dependencies {
+                if let Some(specifier) = specifier.as_str() {
+                    specifiers.insert(name.clone(), specifier.to_string());
+                }
+            }
+        }
+    }
+
+    specifiers
+}
+
+const DEPENDENCIES_FIELDS: [&str; 3] = ["dependencies", "devDependencies", "optionalDependencies"];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn outdated_importers() {
+        let lockfile = Lockfile {
+            importers: Some(indexmap::indexmap! {
+                "packages/abc".into() => indexmap::indexmap! {
+                    "next".into() => "14.0.0".into(),
+                    "lodash".into() => "^4.17.21".into(),
+                    "react".into() => "^18.2.0".into(),
+                },
+            }),
+            ..Default::default()
+        };
+
+        let declared = indexmap::indexmap! {
+            "next".into() => "14.1.0".into(),
+            "react".into() => "^18.2.0".into(),
+            "zod".into() => "^3.22.0".into(),
+        };
+
+        assert_eq!(
+            lockfile.outdated("packages/abc", &declared, &HashSet::new()),
+            vec![
+                ("next".into(), Some("14.1.0".into()), Some("14.0.0".into())),
+                ("zod".into(), Some("^3.22.0".into()), None),
+                ("lodash".into(), None, Some("^4.17.21".into())),
+            ]
+        );
+        assert_eq!(
+            lockfile
+                .outdated("packages/def", &declared, &HashSet::new())
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn outdated_descriptors() {
+        let lockfile = Lockfile {
+            descriptors: Some(HashMap::from([(
+                "react@^18.2.0".into(),
+                "react@^18.2.0".into(),
+            )])),
+            ..Default::default()
+        };
+
+        let declared = indexmap::indexmap! {
+            "abc".into() => "1.0.0".into(),
+            "def".into() => "workspace:*".into(),
+            "next".into() => "14.1.0".into(),
+            "react".into() => "^18.2.0".into(),
+        };
+
+        assert_eq!(
+            lockfile.outdated(".", &declared, &HashSet::from(["abc".into()])),
+            vec![("next".into(), Some("14.1.0".into()), None)]
+        );
+    }
+
+    #[test]
+    fn resolved() {
+        let package = |name: &str, version: &str, dependencies: &[&str]| LockedPackage {
+            name: name.into(),
+            version: version.into(),
+            dependencies: dependencies.iter().map(|key| key.to_string()).collect(),
+        };
+
+        let lockfile = Lockfile {
+            packages: indexmap::indexmap! {
+                "next@14.1.0".into() => package("next", "14.1.0", &["react@18.2.0", "styled-jsx@5.1.1"]),
+                "react@18.2.0".into() => package("react", "18.2.0", &["loose-envify@1.4.0"]),
+                "loose-envify@1.4.0".into() => package("loose-envify", "1.4.0", &[]),
+                "styled-jsx@5.1.1".into() => package("styled-jsx", "5.1.1", &["react@18.2.0"]),
+            },
+            resolved_importers: indexmap::indexmap! {
+                "packages/abc".into() => vec!["next@14.1.0".into(), "react@18.2.0".into()],
+            },
+            ..Default::default()
+        };
+
+        let declared = Specifiers::new();
+        let mut direct = lockfile
+            .resolved("packages/abc", &declared, false)
+            .into_iter()
+            .collect::<Vec<_>>();
+        direct.sort();
+        assert_eq!(direct, vec![("next", "14.1.0"), ("react", "18.2.0")]);
+
+        let mut transitive = lockfile
+            .resolved("packages/abc", &declared, true)
+            .into_iter()
+            .collect::<Vec<_>>();
+        transitive.sort();
+        assert_eq!(
+            transitive,
+            vec![
+                ("loose-envify", "1.4.0"),
+                ("next", "14.1.0"),
+                ("react", "18.2.0"),
+                ("styled-jsx", "5.1.1"),
+            ]
+        );
+        assert!(lockfile
+            .resolved("packages/def", &declared, true)
+            .is_empty());
+    }
+}
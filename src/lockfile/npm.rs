@@ -0,0 +1,218 @@
+use super::{merge_specifiers, LockedPackage, Lockfile, DEPENDENCIES_FIELDS};
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde_json::{Map, Value};
+
+/// Parse a `package-lock.json` file. Since lockfile v2, the `packages` map
+/// contains the root package under `""` and each workspace package under its
+/// path, alongside installed packages under `node_modules/`. Lockfile v1 doesn't
+/// record specifiers, so we can't compare it.
+pub fn parse(value: &str) -> Result<Lockfile> {
+    let value = serde_json::from_str::<Value>(value)?;
+
+    let Some(entries) = value
+        .get("packages")
+        .and_then(|packages| packages.as_object())
+    else {
+        return Ok(Lockfile::default());
+    };
+
+    let mut importers = IndexMap::new();
+    let mut packages = IndexMap::new();
+    let mut resolved_importers = IndexMap::new();
+
+    for (path, package) in entries {
+        if let Some((_, name)) = path.rsplit_once("node_modules/") {
+            // Workspace packages are linked into `node_modules`.
+            if package.get("link").and_then(|link| link.as_bool()) == Some(true) {
+                continue;
+            }
+
+            let name = package
+                .get("name")
+                .and_then(|name| name.as_str())
+                .unwrap_or(name);
+
+            if let Some(version) = package.get("version").and_then(|version| version.as_str()) {
+                packages.insert(
+                    path.clone(),
+                    LockedPackage {
+                        name: name.to_string(),
+                        version: version.to_string(),
+                        dependencies: resolve_dependencies(
+                            entries,
+                            path,
+                            package,
+                            &["dependencies", "optionalDependencies", "peerDependencies"],
+                        ),
+                    },
+                );
+            }
+
+            continue;
+        }
+
+        let importer = match path.as_str() {
+            "" => ".".to_string(),
+            path => path.to_string(),
+        };
+
+        resolved_importers.insert(
+            importer.clone(),
+            resolve_dependencies(entries, path, package, &DEPENDENCIES_FIELDS),
+        );
+        importers.insert(importer, merge_specifiers(package, &DEPENDENCIES_FIELDS));
+    }
+
+    Ok(Lockfile {
+        importers: Some(importers),
+        packages,
+        resolved_importers,
+        ..Default::default()
+    })
+}
+
+/// Resolve dependencies the same way Node.js does, looking for the closest
+/// `node_modules` directory containing them.
+fn resolve_dependencies(
+    entries: &Map<String, Value>,
+    path: &str,
+    package: &Value,
+    fields: &[&str],
+) -> Vec<String> {
+    let mut keys = Vec::new();
+
+    for field in fields {
+        let Some(dependencies) = package.get(field).and_then(|value| value.as_object()) else {
+            continue;
+        };
+
+        for name in dependencies.keys() {
+            let mut base = path;
+
+            loop {
+                let key = match base {
+                    "" => format!("node_modules/{}", name),
+                    base => format!("{}/node_modules/{}", base, name),
+                };
+
+                if entries.contains_key(&key) {
+                    keys.push(key);
+                    break;
+                }
+
+                if base.is_empty() {
+                    break;
+                }
+
+                base = match base.rfind("/node_modules/") {
+                    Some(index) => &base[..index],
+                    None => "",
+                };
+            }
+        }
+    }
+
+    keys
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn v3() {
+        let lockfile = parse(
+            r#"{
+  "name": "root",
+  "lockfileVersion": 3,
+  "packages": {
+    "": {
+      "name": "root",
+      "workspaces": ["packages/*"],
+      "devDependencies": { "typescript": "^5.3.0" }
+    },
+    "node_modules/abc": { "resolved": "packages/abc", "link": true },
+    "node_modules/react": { "version": "18.2.0" },
+    "packages/abc": {
+      "name": "abc",
+      "dependencies": { "react": "^18.2.0" },
+      "optionalDependencies": { "fsevents": "^2.3.0" }
+    }
+  }
+}"#,
+        )
+        .unwrap();
+
+        let importers = lockfile.importers.unwrap();
+        assert_eq!(importers.len(), 2);
+        assert_eq!(importers["."]["typescript"], "^5.3.0");
+        assert_eq!(importers["packages/abc"]["react"], "^18.2.0");
+        assert_eq!(importers["packages/abc"]["fsevents"], "^2.3.0");
+    }
+
+    #[test]
+    fn resolved() {
+        let lockfile = parse(
+            r#"{
+  "lockfileVersion": 3,
+  "packages": {
+    "": {
+      "dependencies": { "abc": "*", "react": "^18.2.0" }
+    },
+    "node_modules/abc": { "resolved": "packages/abc", "link": true },
+    "node_modules/loose-envify": { "version": "1.4.0" },
+    "node_modules/react": {
+      "version": "18.2.0",
+      "dependencies": { "loose-envify": "^1.1.0" }
+    },
+    "node_modules/string-width-cjs": {
+      "name": "string-width",
+      "version": "4.2.3"
+    },
+    "packages/abc": {
+      "dependencies": { "react": "^17.0.0", "string-width-cjs": "npm:string-width@^4.2.0" }
+    },
+    "packages/abc/node_modules/react": {
+      "version": "17.0.2",
+      "dependencies": { "loose-envify": "^1.1.0" }
+    }
+  }
+}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lockfile.resolved_importers["."],
+            vec!["node_modules/abc", "node_modules/react"]
+        );
+        assert_eq!(
+            lockfile.resolved_importers["packages/abc"],
+            vec![
+                "packages/abc/node_modules/react",
+                "node_modules/string-width-cjs"
+            ]
+        );
+        assert_eq!(
+            lockfile.packages["packages/abc/node_modules/react"],
+            LockedPackage {
+                name: "react".into(),
+                version: "17.0.2".into(),
+                dependencies: vec!["node_modules/loose-envify".into()],
+            }
+        );
+        assert_eq!(
+            lockfile.packages["node_modules/string-width-cjs"].name,
+            "string-width"
+        );
+        assert!(!lockfile.packages.contains_key("node_modules/abc"));
+    }
+
+    #[test]
+    fn v1() {
+        let lockfile = parse(r#"{ "lockfileVersion": 1, "dependencies": {} }"#).unwrap();
+
+        assert!(lockfile.importers.is_none());
+        assert!(lockfile.descriptors.is_none());
+    }
+}
@@ -0,0 +1,391 @@
+use super::{LockedPackage, Lockfile, Specifiers, DEPENDENCIES_FIELDS};
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+
+/// How packages are keyed, which changed across lockfile versions.
+#[derive(Debug, Clone, Copy)]
+enum KeyFormat {
+    /// `/react/18.2.0`, `/react-dom/18.2.0_react@18.2.0`
+    V5,
+    /// `/react@18.2.0`, `/react-dom@18.2.0(react@18.2.0)`
+    V6,
+    /// `react@18.2.0`, `react-dom@18.2.0(react@18.2.0)`
+    V9,
+}
+
+impl KeyFormat {
+    fn of(value: &Value) -> Self {
+        let version = match value.get("lockfileVersion") {
+            Some(Value::String(version)) => version.clone(),
+            Some(Value::Number(version)) => version.to_string(),
+            _ => String::new(),
+        };
+
+        match version
+            .split('.')
+            .next()
+            .and_then(|major| major.parse::<u32>().ok())
+        {
+            Some(major) if major >= 9 => KeyFormat::V9,
+            Some(major) if major >= 6 => KeyFormat::V6,
+            _ => KeyFormat::V5,
+        }
+    }
+
+    /// The key of a dependency, from its name and resolved version. Aliased
+    /// dependencies already resolve to a key.
+    fn key(&self, name: &str, version: &str) -> Option<String> {
+        if version.starts_with("link:") {
+            return None;
+        }
+
+        Some(match self {
+            KeyFormat::V5 | KeyFormat::V6 if version.starts_with('/') => version.to_string(),
+            KeyFormat::V5 => format!("/{}/{}", name, version),
+            KeyFormat::V6 => format!("/{}@{}", name, version),
+            KeyFormat::V9 => match split_key(version) {
+                Some(_) => version.to_string(),
+                None => format!("{}@{}", name, version),
+            },
+        })
+    }
+
+    /// The name and version of a package from its key, without peers.
+    fn parse_key(&self, key: &str) -> Option<(String, String)> {
+        let key = key.trim_start_matches('/');
+
+        match self {
+            KeyFormat::V5 => {
+                let (name, version) = key.rsplit_once('/')?;
+                let version = version.split('_').next()?;
+
+                Some((name.to_string(), version.to_string()))
+            }
+            KeyFormat::V6 | KeyFormat::V9 => split_key(key),
+        }
+    }
+}
+
+/// Split a `name@version(peers)` key, where the name can be scoped.
+fn split_key(key: &str) -> Option<(String, String)> {
+    let key = key.split('(').next()?;
+    let index = key.get(1..)?.find('@')? + 1;
+
+    Some((key[..index].to_string(), key[index + 1..].to_string()))
+}
+
+/// Parse a `pnpm-lock.yaml` file. Importers record the specifier of each
+/// dependency, either in a `specifiers` map (lockfile v5) or next to the
+/// resolved version (lockfile v6+). Single-package lockfiles have these fields
+/// at the top level instead.
+pub fn parse(value: &str) -> Result<Lockfile> {
+    let value = serde_yaml::from_str::<Value>(value)?;
+    let format = KeyFormat::of(&value);
+    let mut importers = IndexMap::new();
+    let mut resolved_importers = IndexMap::new();
+
+    let entries = match value
+        .get("importers")
+        .and_then(|importers| importers.as_mapping())
+    {
+        Some(values) => values
+            .iter()
+            .filter_map(|(path, importer)| Some((path.as_str()?, importer)))
+            .collect::<Vec<_>>(),
+        None => vec![(".", &value)],
+    };
+
+    for (path, importer) in entries {
+        importers.insert(path.to_string(), parse_importer(importer));
+        resolved_importers.insert(
+            path.to_string(),
+            resolve_dependencies(importer, format, &DEPENDENCIES_FIELDS),
+        );
+    }
+
+    // Lockfile v9 moved the dependencies of each package to `snapshots`.
+    let mut packages = IndexMap::new();
+
+    if let Some(values) = value
+        .get("snapshots")
+        .or_else(|| value.get("packages"))
+        .and_then(|values| values.as_mapping())
+    {
+        for (key, package) in values {
+            let Some(key) = key.as_str() else {
+                continue;
+            };
+
+            if let Some((name, version)) = format.parse_key(key) {
+                packages.insert(
+                    key.to_string(),
+                    LockedPackage {
+                        name,
+                        version,
+                        dependencies: resolve_dependencies(
+                            package,
+                            format,
+                            &["dependencies", "optionalDependencies"],
+                        ),
+                    },
+                );
+            }
+        }
+    }
+
+    Ok(Lockfile {
+        importers: Some(importers),
+        packages,
+        resolved_importers,
+        ..Default::default()
+    })
+}
+
+/// The keys of the dependencies of an importer or package. Versions are either
+/// listed directly, or next to the specifier for importers since lockfile v6.
+fn resolve_dependencies(value: &Value, format: KeyFormat, fields: &[&str]) -> Vec<String> {
+    let mut keys = Vec::new();
+
+    for field in fields {
+        if let Some(values) = value.get(field).and_then(|values| values.as_mapping()) {
+            for (name, dependency) in values {
+                let version = match dependency {
+                    Value::Mapping(_) => dependency.get("version").and_then(as_string),
+                    _ => as_string(dependency),
+                };
+
+                if let (Some(name), Some(version)) = (name.as_str(), version) {
+                    keys.extend(format.key(name, &version));
+                }
+            }
+        }
+    }
+
+    keys
+}
+
+fn parse_importer(importer: &Value) -> Specifiers {
+    let mut specifiers = Specifiers::new();
+
+    if let Some(values) = importer
+        .get("specifiers")
+        .and_then(|values| values.as_mapping())
+    {
+        for (name, specifier) in values {
+            if let (Some(name), Some(specifier)) = (name.as_str(), as_string(specifier)) {
+                specifiers.insert(name.to_string(), specifier);
+            }
+        }
+
+        return specifiers;
+    }
+
+    for field in DEPENDENCIES_FIELDS {
+        if let Some(values) = importer.get(field).and_then(|values| values.as_mapping()) {
+            for (name, dependency) in values {
+                if let (Some(name), Some(specifier)) = (
+                    name.as_str(),
+                    dependency.get("specifier").and_then(as_string),
+                ) {
+                    specifiers.insert(name.to_string(), specifier);
+                }
+            }
+        }
+    }
+
+    specifiers
+}
+
+/// Specifiers like `1.0.0` or `1` can be parsed as YAML numbers.
+fn as_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn v9() {
+        let lockfile = parse(
+            r#"lockfileVersion: '9.0'
+
+importers:
+
+  .:
+    devDependencies:
+      typescript:
+        specifier: ^5.3.0
+        version: 5.3.3
+
+  packages/abc:
+    dependencies:
+      def:
+        specifier: workspace:*
+        version: link:../def
+      react:
+        specifier: ^18.2.0
+        version: 18.2.0
+"#,
+        )
+        .unwrap();
+
+        let importers = lockfile.importers.unwrap();
+        assert_eq!(importers.len(), 2);
+        assert_eq!(importers["."]["typescript"], "^5.3.0");
+        assert_eq!(importers["packages/abc"]["def"], "workspace:*");
+        assert_eq!(importers["packages/abc"]["react"], "^18.2.0");
+    }
+
+    #[test]
+    fn v9_resolved() {
+        let lockfile = parse(
+            r#"lockfileVersion: '9.0'
+
+importers:
+
+  .:
+    dependencies:
+      next:
+        specifier: ^14.1.0
+        version: 14.1.0(react@18.2.0)
+      string-width-cjs:
+        specifier: npm:string-width@^4.2.0
+        version: string-width@4.2.3
+
+packages:
+
+  '@next/env@14.1.0':
+    resolution: {integrity: sha512-0}
+
+  next@14.1.0:
+    resolution: {integrity: sha512-1}
+
+snapshots:
+
+  '@next/env@14.1.0': {}
+
+  next@14.1.0(react@18.2.0):
+    dependencies:
+      '@next/env': 14.1.0
+      react: 18.2.0
+
+  react@18.2.0: {}
+
+  string-width@4.2.3: {}
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lockfile.resolved_importers["."],
+            vec!["next@14.1.0(react@18.2.0)", "string-width@4.2.3"]
+        );
+        assert_eq!(lockfile.packages.len(), 4);
+        assert_eq!(lockfile.packages["@next/env@14.1.0"].name, "@next/env");
+        assert_eq!(
+            lockfile.packages["next@14.1.0(react@18.2.0)"],
+            LockedPackage {
+                name: "next".into(),
+                version: "14.1.0".into(),
+                dependencies: vec!["@next/env@14.1.0".into(), "react@18.2.0".into()],
+            }
+        );
+    }
+
+    #[test]
+    fn v6_resolved() {
+        let lockfile = parse(
+            r#"lockfileVersion: '6.0'
+
+dependencies:
+  react-dom:
+    specifier: ^18.2.0
+    version: 18.2.0(react@18.2.0)
+
+packages:
+
+  /react-dom@18.2.0(react@18.2.0):
+    resolution: {integrity: sha512-0}
+    dependencies:
+      react: 18.2.0
+
+  /react@18.2.0:
+    resolution: {integrity: sha512-1}
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            lockfile.resolved_importers["."],
+            vec!["/react-dom@18.2.0(react@18.2.0)"]
+        );
+        assert_eq!(
+            lockfile.packages["/react-dom@18.2.0(react@18.2.0)"].dependencies,
+            vec!["/react@18.2.0"]
+        );
+        assert_eq!(lockfile.packages["/react@18.2.0"].version, "18.2.0");
+    }
+
+    #[test]
+    fn v5() {
+        let lockfile = parse(
+            r#"lockfileVersion: 5.4
+
+importers:
+
+  .:
+    specifiers:
+      typescript: ^5.3.0
+    devDependencies:
+      typescript: 5.3.3
+
+  packages/abc:
+    specifiers:
+      react: 18
+    dependencies:
+      react: 18.2.0
+"#,
+        )
+        .unwrap();
+
+        let importers = lockfile.importers.unwrap();
+        assert_eq!(importers["."]["typescript"], "^5.3.0");
+        assert_eq!(importers["packages/abc"]["react"], "18");
+        assert_eq!(lockfile.resolved_importers["."], vec!["/typescript/5.3.3"]);
+    }
+
+    #[test]
+    fn v5_keys() {
+        assert_eq!(
+            KeyFormat::V5.parse_key("/@babel/core/7.23.0_supports-color@8.1.1"),
+            Some(("@babel/core".into(), "7.23.0".into()))
+        );
+        assert_eq!(
+            KeyFormat::V5.key("string-width-cjs", "/string-width/4.2.3"),
+            Some("/string-width/4.2.3".into())
+        );
+        assert_eq!(KeyFormat::V5.key("abc", "link:../abc"), None);
+    }
+
+    #[test]
+    fn single_package() {
+        let lockfile = parse(
+            r#"lockfileVersion: '6.0'
+
+dependencies:
+  react:
+    specifier: ^18.2.0
+    version: 18.2.0
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(lockfile.importers.unwrap()["."]["react"], "^18.2.0");
+    }
+}
@@ -0,0 +1,351 @@
+use super::{LockedPackage, Lockfile, Specifiers};
+use anyhow::Result;
+use indexmap::IndexMap;
+use serde_yaml::Value;
+use std::collections::HashMap;
+
+/// Parse a `yarn.lock` file, either from Yarn Berry (valid YAML with a
+/// `__metadata` entry) or Yarn classic.
+pub fn parse(value: &str) -> Result<Lockfile> {
+    if value.lines().any(|line| line.starts_with("__metadata:")) {
+        return parse_berry(value);
+    }
+
+    Ok(parse_classic(value))
+}
+
+/// Split an entry key into its descriptors, e.g.
+/// `"react@npm:^18.0.0, react@npm:^18.2.0"`.
+fn descriptors(key: &str) -> impl Iterator<Item = &str> {
+    key.split(',')
+        .map(|descriptor| descriptor.trim().trim_matches('"'))
+        .filter(|descriptor| !descriptor.is_empty())
+}
+
+/// Split a `name@range` descriptor, where the name can be scoped.
+fn split_descriptor(descriptor: &str) -> Option<(&str, &str)> {
+    let index = descriptor.get(1..)?.find('@')? + 1;
+
+    Some((&descriptor[..index], &descriptor[index + 1..]))
+}
+
+/// The name of the package a descriptor resolves to, following aliases
+/// (`string-width-cjs@npm:string-width@^4.2.0`).
+fn package_name(descriptor: &str) -> Option<&str> {
+    let (name, range) = split_descriptor(descriptor)?;
+
+    match range.strip_prefix("npm:").and_then(split_descriptor) {
+        Some((name, _)) => Some(name),
+        None => Some(name),
+    }
+}
+
+/// Yarn Berry records workspace packages as `name@workspace:path` entries, with
+/// their dependencies and devDependencies merged into `dependencies`.
+fn parse_berry(value: &str) -> Result<Lockfile> {
+    let value = serde_yaml::from_str::<Value>(value)?;
+    let mut importers = IndexMap::new();
+    let mut entries = Vec::new();
+    let mut keys = HashMap::new();
+
+    if let Some(values) = value.as_mapping() {
+        for (key, entry) in values {
+            let Some(key) = key.as_str() else {
+                continue;
+            };
+
+            let mut dependencies = Specifiers::new();
+
+            for field in ["dependencies", "optionalDependencies"] {
+                if let Some(values) = entry.get(field).and_then(|values| values.as_mapping()) {
+                    for (name, specifier) in values {
+                        if let (Some(name), Some(specifier)) = (name.as_str(), specifier.as_str()) {
+                            dependencies.insert(name.to_string(), specifier.to_string());
+                        }
+                    }
+                }
+            }
+
+            for descriptor in descriptors(key) {
+                keys.insert(descriptor.to_string(), key.to_string());
+            }
+
+            entries.push((key, entry, dependencies));
+        }
+    }
+
+    // Recent Yarn versions prefix dependencies with the `npm:` protocol in entry
+    // keys, but older ones don't in `dependencies`.
+    let resolve = |dependencies: &Specifiers| {
+        dependencies
+            .iter()
+            .filter_map(|(name, specifier)| {
+                keys.get(&format!("{}@{}", name, specifier))
+                    .or_else(|| keys.get(&format!("{}@npm:{}", name, specifier)))
+                    .cloned()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut packages = IndexMap::new();
+    let mut resolved_importers = IndexMap::new();
+
+    for (key, entry, dependencies) in entries {
+        let workspace = descriptors(key)
+            .find_map(|descriptor| descriptor.split_once("@workspace:"))
+            .map(|(_, path)| path);
+
+        if let Some(path) = workspace {
+            resolved_importers.insert(path.to_string(), resolve(&dependencies));
+            importers.insert(
+                path.to_string(),
+                dependencies
+                    .into_iter()
+                    .map(|(name, specifier)| (name, normalize(&specifier)))
+                    .collect(),
+            );
+
+            continue;
+        }
+
+        let name = entry
+            .get("resolution")
+            .and_then(|resolution| resolution.as_str())
+            .and_then(package_name);
+        let version = entry.get("version").and_then(|version| version.as_str());
+
+        if let (Some(name), Some(version)) = (name, version) {
+            packages.insert(
+                key.to_string(),
+                LockedPackage {
+                    name: name.to_string(),
+                    version: version.to_string(),
+                    dependencies: resolve(&dependencies),
+                },
+            );
+        }
+    }
+
+    Ok(Lockfile {
+        importers: Some(importers),
+        packages,
+        resolved_importers,
+        ..Default::default()
+    })
+}
+
+/// Recent Yarn versions prefix semver ranges with the `npm:` protocol, while
+/// aliases (`npm:name@range`) are declared with it.
+fn normalize(specifier: &str) -> String {
+    match specifier.strip_prefix("npm:") {
+        Some(range) if !range.contains('@') => range.to_string(),
+        _ => specifier.to_string(),
+    }
+}
+
+/// Yarn classic doesn't record workspace packages, only the descriptors
+/// resolved for all of them. Entries aren't valid YAML, e.g.
+///
+/// ```text
+/// "@babel/code-frame@^7.0.0", "@babel/code-frame@^7.22.13":
+///   version "7.22.13"
+///   dependencies:
+///     "@babel/highlight" "^7.22.13"
+/// ```
+fn parse_classic(value: &str) -> Lockfile {
+    let mut entries = Vec::<(String, Option<String>, Specifiers)>::new();
+    let mut in_dependencies = false;
+
+    for line in value.lines() {
+        if line.trim().is_empty() || line.trim_start().starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with(' ') {
+            if let Some(key) = line.trim_end().strip_suffix(':') {
+                entries.push((key.to_string(), None, Specifiers::new()));
+                in_dependencies = false;
+            }
+
+            continue;
+        }
+
+        let Some((_, version, dependencies)) = entries.last_mut() else {
+            continue;
+        };
+
+        if let Some(line) = line.strip_prefix("    ") {
+            if in_dependencies {
+                if let Some((name, specifier)) = split_field(line) {
+                    dependencies.insert(name, specifier);
+                }
+            }
+        } else if let Some(line) = line.strip_prefix("  ") {
+            in_dependencies = matches!(line, "dependencies:" | "optionalDependencies:");
+
+            if let Some(("version", value)) = split_field(line)
+                .as_ref()
+                .map(|(field, value)| (field.as_str(), value.clone()))
+            {
+                *version = Some(value);
+            }
+        }
+    }
+
+    let mut resolved = HashMap::new();
+
+    for (key, _, _) in &entries {
+        for descriptor in descriptors(key) {
+            resolved.insert(descriptor.to_string(), key.clone());
+        }
+    }
+
+    let mut packages = IndexMap::new();
+
+    for (key, version, dependencies) in entries {
+        let name = descriptors(&key).next().and_then(package_name);
+
+        if let (Some(name), Some(version)) = (name, version) {
+            let dependencies = dependencies
+                .iter()
+                .filter_map(|(name, specifier)| {
+                    resolved.get(&format!("{}@{}", name, specifier)).cloned()
+                })
+                .collect();
+
+            packages.insert(
+                key.clone(),
+                LockedPackage {
+                    name: name.to_string(),
+                    version,
+                    dependencies,
+                },
+            );
+        }
+    }
+
+    Lockfile {
+        descriptors: Some(resolved),
+        packages,
+        ..Default::default()
+    }
+}
+
+/// Split a `name "value"` line, where both parts can be quoted.
+fn split_field(line: &str) -> Option<(String, String)> {
+    let line = line.trim();
+    let (name, value) = match line.strip_prefix('"') {
+        Some(line) => {
+            let (name, value) = line.split_once('"')?;
+            (name, value)
+        }
+        None => line.split_once(' ')?,
+    };
+
+    Some((name.to_string(), value.trim().trim_matches('"').to_string()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn berry() {
+        let lockfile = parse(
+            r#"# This file is generated by running "yarn install" inside your project.
+
+__metadata:
+  version: 8
+  cacheKey: 10c0
+
+"abc@workspace:packages/abc":
+  version: 0.0.0-use.local
+  resolution: "abc@workspace:packages/abc"
+  dependencies:
+    def: "workspace:*"
+    react: "npm:^18.2.0"
+    string-width-cjs: "npm:string-width@^4.2.0"
+  languageName: unknown
+  linkType: soft
+
+"react@npm:^18.0.0, react@npm:^18.2.0":
+  version: 18.2.0
+  resolution: "react@npm:18.2.0"
+  languageName: node
+  linkType: hard
+
+"root@workspace:.":
+  version: 0.0.0-use.local
+  resolution: "root@workspace:."
+  languageName: unknown
+  linkType: soft
+"#,
+        )
+        .unwrap();
+
+        let importers = lockfile.importers.unwrap();
+        assert_eq!(importers.len(), 2);
+        assert!(importers["."].is_empty());
+        assert_eq!(importers["packages/abc"]["def"], "workspace:*");
+        assert_eq!(importers["packages/abc"]["react"], "^18.2.0");
+        assert_eq!(
+            importers["packages/abc"]["string-width-cjs"],
+            "npm:string-width@^4.2.0"
+        );
+
+        assert_eq!(
+            lockfile.resolved_importers["packages/abc"],
+            vec!["react@npm:^18.0.0, react@npm:^18.2.0"]
+        );
+        assert_eq!(lockfile.packages.len(), 1);
+        assert_eq!(
+            lockfile.packages["react@npm:^18.0.0, react@npm:^18.2.0"].version,
+            "18.2.0"
+        );
+    }
+
+    #[test]
+    fn classic() {
+        let lockfile = parse(
+            r#"# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.
+# yarn lockfile v1
+
+
+"@babel/code-frame@^7.0.0", "@babel/code-frame@^7.22.13":
+  version "7.22.13"
+  resolved "https://registry.yarnpkg.com/@babel/code-frame/-/code-frame-7.22.13.tgz"
+
+react@^18.2.0:
+  version "18.2.0"
+  resolved "https://registry.yarnpkg.com/react/-/react-18.2.0.tgz"
+  dependencies:
+    loose-envify "^1.1.0"
+
+loose-envify@^1.1.0:
+  version "1.4.0"
+"#,
+        )
+        .unwrap();
+
+        assert!(lockfile.importers.is_none());
+
+        let descriptors = lockfile.descriptors.unwrap();
+        assert_eq!(descriptors.len(), 4);
+        assert_eq!(
+            descriptors["@babel/code-frame@^7.22.13"],
+            r#""@babel/code-frame@^7.0.0", "@babel/code-frame@^7.22.13""#
+        );
+        assert_eq!(
+            lockfile.packages["react@^18.2.0"],
+            LockedPackage {
+                name: "react".into(),
+                version: "18.2.0".into(),
+                dependencies: vec!["loose-envify@^1.1.0".into()],
+            }
+        );
+        assert_eq!(
+            lockfile.packages[r#""@babel/code-frame@^7.0.0", "@babel/code-frame@^7.22.13""#].name,
+            "@babel/code-frame"
+        );
+    }
+}
@@ -1,16 +1,29 @@
+use crate::fix::EditPlan;
+use crate::lockfile::LockfileKind;
 use crate::printer::print_success;
-use crate::rules::IssueLevel;
+use crate::rules::{IssueLevel, IssuesList};
 use crate::{args::Args, printer::print_error};
 use clap::Parser;
-use collect::{collect_issues, collect_packages};
+use collect::{
+    collect_cross_workspace_issues, collect_issues, collect_packages,
+    collect_workspace_dependencies, find_root, find_scope,
+};
 use colored::Colorize;
-use printer::{print_footer, print_issues};
-use std::time::Instant;
+use packages::PackagesList;
+use printer::{print_footer, print_issues, print_workspace};
+use std::{
+    fs,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+    time::Instant,
+};
 
 mod args;
 mod collect;
+mod fix;
 mod install;
 mod json;
+mod lockfile;
 mod packages;
 mod plural;
 mod printer;
@@ -20,20 +33,86 @@ fn is_ci() -> bool {
     std::env::var("CI").is_ok()
 }
 
-fn main() {
-    let now = Instant::now();
-    let args = Args::parse();
-
-    let packages_list = match collect_packages(&args.path) {
+fn collect_workspace(path: &Path) -> PackagesList {
+    match collect_packages(path) {
         Ok(result) => result,
         Err(error) => {
             print_error("Failed to collect packages", error.to_string().as_str());
             std::process::exit(1);
         }
+    }
+}
+
+fn main() {
+    let now = Instant::now();
+    let mut args = Args::parse();
+
+    // Prompts can't be answered from pipes or git hooks.
+    if !std::io::stdin().is_terminal() {
+        args.non_interactive = true;
+    }
+
+    // When run from a package, lint the monorepo it belongs to.
+    let invoked_path = fs::canonicalize(&args.path).ok();
+
+    if let (Some(invoked_path), Some(root)) = (&invoked_path, find_root(&args.path)) {
+        if *invoked_path != root {
+            // Report paths relative to the monorepo root.
+            if let Err(error) = std::env::set_current_dir(&root) {
+                print_error(
+                    "Failed to find the monorepo root",
+                    error.to_string().as_str(),
+                );
+                std::process::exit(1);
+            }
+
+            args.path = PathBuf::from(".");
+        }
+    }
+
+    let mut workspaces = vec![collect_workspace(&args.path)];
+
+    // Only report the issues of the package the command is run from.
+    let scope = match (&invoked_path, args.all) {
+        (Some(invoked_path), false) => find_scope(&workspaces[0], invoked_path),
+        _ => None,
     };
 
-    let mut config = packages_list.root_package.get_config().unwrap_or_default();
-    config.merge(args);
+    if let Some(scope) = &scope {
+        println!(
+            " {}",
+            format!(
+                "Note: only reporting issues of {}, use `--all` to report issues of the whole monorepo...",
+                scope
+            )
+            .bright_black(),
+        );
+        println!();
+    }
+
+    let mut index = 0;
+
+    // Nested workspaces can contain other nested workspaces.
+    while scope.is_none() && index < workspaces.len() {
+        let nested_workspaces = workspaces[index].nested_workspaces.clone();
+
+        for path in nested_workspaces {
+            workspaces.push(collect_workspace(&path));
+        }
+
+        index += 1;
+    }
+
+    // Each workspace is linted with its own config.
+    let configs = workspaces
+        .iter()
+        .map(|workspace| {
+            let mut config = workspace.root_package.get_config().unwrap_or_default();
+            config.merge(args.clone());
+            config
+        })
+        .collect::<Vec<_>>();
+    let config = &configs[0];
 
     if config.fix && is_ci() {
         print_error(
@@ -43,8 +122,59 @@ fn main() {
         std::process::exit(1);
     }
 
-    let total_packages = packages_list.packages.len();
-    let mut issues = collect_issues(&config, packages_list);
+    let cross_workspace_dependencies =
+        match config.nested_workspaces.cross_workspace && workspaces.len() > 1 {
+            true => workspaces
+                .iter()
+                .zip(&configs)
+                .map(|(workspace, config)| collect_workspace_dependencies(config, workspace))
+                .collect::<Vec<_>>(),
+            false => Vec::new(),
+        };
+
+    let total_packages = match scope {
+        Some(_) => 1,
+        None => workspaces
+            .iter()
+            .map(|workspace| workspace.packages.len())
+            .sum::<usize>(),
+    };
+
+    let root = PathBuf::from(workspaces[0].root_package.get_path());
+    let mut issues_lists = workspaces
+        .into_iter()
+        .zip(&configs)
+        .enumerate()
+        .map(|(index, (workspace, config))| {
+            let path = workspace.root_package.get_path();
+            let title = match index {
+                0 => None,
+                _ => Some(format!("Nested workspace {}", path)),
+            };
+
+            (
+                title,
+                PathBuf::from(path),
+                collect_issues(config, workspace),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(scope) = &scope {
+        for (_, _, issues) in issues_lists.iter_mut() {
+            issues.retain_package(scope);
+        }
+    }
+
+    if !cross_workspace_dependencies.is_empty() {
+        issues_lists.push((
+            Some("Across nested workspaces".to_string()),
+            root,
+            collect_cross_workspace_issues(config, cross_workspace_dependencies),
+        ));
+    }
+
+    let mut transaction = None;
 
     if config.fix {
         if let Some(autofix_select) = &config.select {
@@ -53,36 +183,97 @@ fn main() {
                 format!("Note: automatically selecting {} dependencies for `multiple-dependency-versions` rule...", autofix_select).bright_black(),
             );
             println!();
+        } else if config.non_interactive {
+            println!(
+                " {}",
+                "Note: running non-interactively, use `--select` to fix the `multiple-dependency-versions` rule...".bright_black(),
+            );
+            println!();
         }
 
-        if let Err(error) = issues.fix() {
-            print_error("Failed to fix issues", error.to_string().as_str());
-            std::process::exit(1);
+        // All fixes contribute to a single edit plan, so each file is parsed
+        // and written once, and nothing is written if a fix fails.
+        let plan = EditPlan::default();
+
+        for (_, root, issues) in issues_lists.iter_mut() {
+            if let Err(error) = issues.fix(root, &plan, !config.non_interactive) {
+                print_error("Failed to fix issues", error.to_string().as_str());
+                std::process::exit(1);
+            }
+        }
+
+        match plan.commit() {
+            Ok(result) => transaction = Some(result),
+            Err(error) => {
+                print_error("Failed to fix issues", error.to_string().as_str());
+                std::process::exit(1);
+            }
         }
     }
 
-    let total_issues = issues.total_len();
+    let count = |len: fn(&IssuesList) -> usize| {
+        issues_lists
+            .iter()
+            .map(|(_, _, issues)| len(issues))
+            .sum::<usize>()
+    };
+    let total_issues = count(|issues| issues.total_len());
 
     if total_issues == 0 {
         print_success();
         return;
     }
 
-    let warnings = issues.len_by_level(IssueLevel::Warning);
-    let errors = issues.len_by_level(IssueLevel::Error);
-    let fixed = issues.len_by_level(IssueLevel::Fixed);
+    let warnings = count(|issues| issues.len_by_level(IssueLevel::Warning));
+    let errors = count(|issues| issues.len_by_level(IssueLevel::Error));
+    let fixed = count(|issues| issues.len_by_level(IssueLevel::Fixed));
 
     // Only run the install command if we allow it and we fixed some issues.
-    if config.fix && !config.no_install && fixed > 0 {
-        if let Err(error) = install::install() {
-            print_error("Failed to install packages", error.to_string().as_str());
-            std::process::exit(1);
+    if let Some(mut transaction) = transaction.filter(|_| !config.no_install && fixed > 0) {
+        let mut installed_roots = Vec::new();
+
+        for (_, root, issues) in &issues_lists {
+            if issues.len_by_level(IssueLevel::Fixed) == 0 || installed_roots.contains(&root) {
+                continue;
+            }
+
+            // Lockfiles are restored with the fixed files if the install fails.
+            let result = LockfileKind::ALL
+                .iter()
+                .try_for_each(|lockfile_kind| {
+                    transaction.backup(&root.join(lockfile_kind.file_name()))
+                })
+                .and_then(|_| install::install(root, !config.non_interactive));
+
+            if let Err(error) = result {
+                let message = match transaction.rollback() {
+                    Ok(()) => format!("{}, all fixes were reverted", error),
+                    Err(rollback_error) => {
+                        format!("{}, and failed to revert fixes: {}", error, rollback_error)
+                    }
+                };
+
+                print_error("Failed to install packages", message.as_str());
+                std::process::exit(1);
+            }
+
+            installed_roots.push(root);
         }
     }
 
-    if let Err(error) = print_issues(issues) {
-        print_error("Failed to print issues", error.to_string().as_str());
-        std::process::exit(1);
+    for (title, _, issues) in issues_lists {
+        if issues.total_len() == 0 {
+            continue;
+        }
+
+        if let Some(title) = title {
+            print_workspace(&title);
+        }
+
+        if let Err(error) = print_issues(issues) {
+            print_error("Failed to print issues", error.to_string().as_str());
+            std::process::exit(1);
+        }
     }
 
     print_footer(total_issues, total_packages, warnings, errors, fixed, now);
@@ -0,0 +1,237 @@
+use serde_json::Value;
+use std::{fmt::Display, path::Path};
+
+#[derive(Debug, PartialEq)]
+pub enum ExportsProblem {
+    MixedKeys,
+    InvalidSubpath,
+    NestedSubpath,
+    TypesNotFirst,
+    DefaultNotLast,
+    NotRelative,
+    NonExistantFile,
+}
+
+impl Display for ExportsProblem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MixedKeys => write!(f, "mixes subpath and condition keys."),
+            Self::InvalidSubpath => write!(f, "subpath should start with \"./\"."),
+            Self::NestedSubpath => write!(f, "subpaths are only allowed at the top level."),
+            Self::TypesNotFirst => write!(f, "types should be the first condition."),
+            Self::DefaultNotLast => write!(f, "default should be the last condition."),
+            Self::NotRelative => write!(f, "target should start with \"./\"."),
+            Self::NonExistantFile => write!(f, "but this file doesn't exist."),
+        }
+    }
+}
+
+/// A problem found in the `exports` field, along with the keys leading to it
+/// and the target if the problem is about a target.
+#[derive(Debug, PartialEq)]
+pub struct ExportsError {
+    pub keys: Vec<String>,
+    pub target: Option<String>,
+    pub problem: ExportsProblem,
+}
+
+/// Validate the shape of an `exports` field and, given the package directory,
+/// check that its targets exist relative to it.
+///
+/// See https://nodejs.org/api/packages.html#package-entry-points
+pub fn validate(exports: &Value, package_path: Option<&Path>) -> Vec<ExportsError> {
+    let mut errors = Vec::new();
+
+    match exports {
+        Value::Object(values) => {
+            let subpaths = values.keys().filter(|key| key.starts_with('.')).count();
+
+            if subpaths == 0 {
+                validate_conditions(values, Vec::new(), package_path, &mut errors);
+            } else if subpaths != values.len() {
+                errors.push(ExportsError {
+                    keys: Vec::new(),
+                    target: None,
+                    problem: ExportsProblem::MixedKeys,
+                });
+            } else {
+                for (subpath, value) in values {
+                    let keys = vec![subpath.clone()];
+
+                    if subpath != "." && !subpath.starts_with("./") {
+                        errors.push(ExportsError {
+                            keys: keys.clone(),
+                            target: None,
+                            problem: ExportsProblem::InvalidSubpath,
+                        });
+                    }
+
+                    validate_value(value, keys, package_path, &mut errors);
+                }
+            }
+        }
+        value => validate_value(value, Vec::new(), package_path, &mut errors),
+    }
+
+    errors
+}
+
+fn validate_value(
+    value: &Value,
+    keys: Vec<String>,
+    package_path: Option<&Path>,
+    errors: &mut Vec<ExportsError>,
+) {
+    match value {
+        Value::String(target) => {
+            if !target.starts_with("./") {
+                errors.push(ExportsError {
+                    keys,
+                    target: Some(target.clone()),
+                    problem: ExportsProblem::NotRelative,
+                });
+            } else if !target.contains('*')
+                && package_path.is_some_and(|package_path| !package_path.join(target).exists())
+            {
+                errors.push(ExportsError {
+                    keys,
+                    target: Some(target.clone()),
+                    problem: ExportsProblem::NonExistantFile,
+                });
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                validate_value(value, keys.clone(), package_path, errors);
+            }
+        }
+        Value::Object(values) => {
+            if values.keys().any(|key| key.starts_with('.')) {
+                errors.push(ExportsError {
+                    keys,
+                    target: None,
+                    problem: ExportsProblem::NestedSubpath,
+                });
+                return;
+            }
+
+            validate_conditions(values, keys, package_path, errors);
+        }
+        _ => {}
+    }
+}
+
+fn validate_conditions(
+    values: &serde_json::Map<String, Value>,
+    keys: Vec<String>,
+    package_path: Option<&Path>,
+    errors: &mut Vec<ExportsError>,
+) {
+    if values.is_empty() {
+        return;
+    }
+
+    let last = values.len() - 1;
+
+    for (index, (condition, value)) in values.iter().enumerate() {
+        let mut keys = keys.clone();
+        keys.push(condition.clone());
+
+        if condition == "types" && index != 0 {
+            errors.push(ExportsError {
+                keys: keys.clone(),
+                target: None,
+                problem: ExportsProblem::TypesNotFirst,
+            });
+        } else if condition == "default" && index != last {
+            errors.push(ExportsError {
+                keys: keys.clone(),
+                target: None,
+                problem: ExportsProblem::DefaultNotLast,
+            });
+        }
+
+        validate_value(value, keys, package_path, errors);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    fn problems(exports: Value) -> Vec<ExportsProblem> {
+        validate(&exports, Some(Path::new("fixtures/exports/packages/valid")))
+            .into_iter()
+            .map(|error| error.problem)
+            .collect()
+    }
+
+    #[test]
+    fn valid() {
+        assert!(problems(json!("./index.js")).is_empty());
+        assert!(problems(json!({
+            ".": {
+                "types": "./index.d.ts",
+                "import": "./index.js",
+                "default": "./index.js"
+            },
+            "./utils/*": "./utils/*.js",
+            "./internal": null
+        }))
+        .is_empty());
+        assert!(problems(json!({
+            "types": "./index.d.ts",
+            "default": "./index.js"
+        }))
+        .is_empty());
+    }
+
+    #[test]
+    fn mixed_keys() {
+        assert_eq!(
+            problems(json!({
+                ".": "./index.js",
+                "import": "./index.js"
+            })),
+            vec![ExportsProblem::MixedKeys]
+        );
+    }
+
+    #[test]
+    fn subpaths() {
+        assert_eq!(
+            problems(json!({ "./index": "./index.js", ".utils": "./index.js" })),
+            vec![ExportsProblem::InvalidSubpath]
+        );
+        assert_eq!(
+            problems(json!({ ".": { "./nested": "./index.js" } })),
+            vec![ExportsProblem::NestedSubpath]
+        );
+    }
+
+    #[test]
+    fn conditions_order() {
+        assert_eq!(
+            problems(json!({
+                "default": "./index.js",
+                "types": "./index.d.ts"
+            })),
+            vec![
+                ExportsProblem::DefaultNotLast,
+                ExportsProblem::TypesNotFirst
+            ]
+        );
+    }
+
+    #[test]
+    fn targets() {
+        assert_eq!(
+            problems(json!({ ".": ["index.js", "./missing.js"] })),
+            vec![ExportsProblem::NotRelative, ExportsProblem::NonExistantFile]
+        );
+
+        // Without the package directory, only the shape is checked.
+        assert!(validate(&json!({ ".": "./missing.js" }), None).is_empty());
+    }
+}
@@ -0,0 +1,408 @@
+/// Node.js built-in modules, which can be imported with or without the `node:` protocol.
+/// See https://nodejs.org/api/module.html#modulebuiltinmodules
+pub const NODE_BUILTINS: [&str; 42] = [
+    "assert",
+    "async_hooks",
+    "buffer",
+    "child_process",
+    "cluster",
+    "console",
+    "constants",
+    "crypto",
+    "dgram",
+    "diagnostics_channel",
+    "dns",
+    "domain",
+    "events",
+    "fs",
+    "http",
+    "http2",
+    "https",
+    "inspector",
+    "module",
+    "net",
+    "os",
+    "path",
+    "perf_hooks",
+    "process",
+    "punycode",
+    "querystring",
+    "readline",
+    "repl",
+    "stream",
+    "string_decoder",
+    "sys",
+    "timers",
+    "tls",
+    "trace_events",
+    "tty",
+    "url",
+    "util",
+    "v8",
+    "vm",
+    "wasi",
+    "worker_threads",
+    "zlib",
+];
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Ident(&'a str),
+    Str(&'a str),
+    Punct(char),
+    /// A template literal with expressions, which isn't a static specifier.
+    Template,
+}
+
+/// Split JS/TS source into the tokens we need to find imports, skipping
+/// comments, regular expressions and the content of template expressions.
+fn tokenize(source: &str) -> Vec<Token<'_>> {
+    let bytes = source.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+
+        match byte {
+            b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    index += 1;
+                }
+            }
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                index += 2;
+
+                while index < bytes.len()
+                    && !(bytes[index] == b'*' && bytes.get(index + 1) == Some(&b'/'))
+                {
+                    index += 1;
+                }
+
+                index += 2;
+            }
+            // A slash after an operator or at the start of the source is a regular
+            // expression, which can contain quotes.
+            b'/' if matches!(
+                tokens.last(),
+                None | Some(Token::Punct(
+                    '(' | ',' | '=' | ':' | '[' | '!' | '&' | '|' | '?' | '{' | '}' | ';'
+                ))
+            ) =>
+            {
+                let mut in_class = false;
+                index += 1;
+
+                while index < bytes.len() && bytes[index] != b'\n' {
+                    match bytes[index] {
+                        b'\\' => index += 1,
+                        b'[' => in_class = true,
+                        b']' => in_class = false,
+                        b'/' if !in_class => break,
+                        _ => {}
+                    }
+
+                    index += 1;
+                }
+
+                index += 1;
+                tokens.push(Token::Punct('/'));
+            }
+            b'\'' | b'"' => {
+                let start = index + 1;
+                index += 1;
+
+                // Strings can't span multiple lines, which limits the damage of
+                // an unbalanced quote (e.g. in JSX text).
+                while index < bytes.len() && bytes[index] != byte && bytes[index] != b'\n' {
+                    if bytes[index] == b'\\' {
+                        index += 1;
+                    }
+
+                    index += 1;
+                }
+
+                tokens.push(Token::Str(&source[start..index.min(bytes.len())]));
+                index += 1;
+            }
+            b'`' => {
+                let start = index + 1;
+                let mut has_expressions = false;
+                let mut depth = 0;
+                index += 1;
+
+                while index < bytes.len() && (depth > 0 || bytes[index] != b'`') {
+                    match bytes[index] {
+                        b'\\' => index += 1,
+                        b'$' if depth == 0 && bytes.get(index + 1) == Some(&b'{') => {
+                            has_expressions = true;
+                            depth += 1;
+                            index += 1;
+                        }
+                        b'{' if depth > 0 => depth += 1,
+                        b'}' if depth > 0 => depth -= 1,
+                        _ => {}
+                    }
+
+                    index += 1;
+                }
+
+                tokens.push(match has_expressions {
+                    true => Token::Template,
+                    false => Token::Str(&source[start..index.min(bytes.len())]),
+                });
+                index += 1;
+            }
+            byte if byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'$' => {
+                let start = index;
+
+                while index < bytes.len()
+                    && (bytes[index].is_ascii_alphanumeric()
+                        || bytes[index] == b'_'
+                        || bytes[index] == b'$')
+                {
+                    index += 1;
+                }
+
+                tokens.push(Token::Ident(&source[start..index]));
+            }
+            byte if byte.is_ascii_whitespace() => index += 1,
+            _ => {
+                let char = source[index..].chars().next().unwrap_or_default();
+
+                tokens.push(Token::Punct(char));
+                index += char.len_utf8().max(1);
+            }
+        }
+    }
+
+    tokens
+}
+
+/// An import specifier, and whether only its types are imported (e.g. with
+/// `import type`), which TypeScript removes from the output.
+#[derive(Debug, PartialEq)]
+pub struct Import<'a> {
+    pub specifier: &'a str,
+    pub type_only: bool,
+}
+
+impl<'a> Import<'a> {
+    fn runtime(specifier: &'a str) -> Self {
+        Self {
+            specifier,
+            type_only: false,
+        }
+    }
+}
+
+/// Find the specifiers of all static and dynamic imports, re-exports and
+/// `require` calls in JS/TS source.
+pub fn parse_imports(source: &str) -> Vec<Import<'_>> {
+    let tokens = tokenize(source);
+    let mut specifiers = Vec::new();
+    let mut in_statement = false;
+    let mut type_only = false;
+
+    for (index, token) in tokens.iter().enumerate() {
+        let previous = index.checked_sub(1).and_then(|index| tokens.get(index));
+        let next = tokens.get(index + 1);
+        let after_next = tokens.get(index + 2);
+
+        // Skip property accesses like `foo.import` or `module.require`.
+        if previous == Some(&Token::Punct('.')) {
+            continue;
+        }
+
+        match token {
+            Token::Ident("import") => match (next, after_next) {
+                (Some(Token::Str(specifier)), _) => specifiers.push(Import::runtime(specifier)),
+                (Some(Token::Punct('(')), Some(Token::Str(specifier))) => {
+                    specifiers.push(Import::runtime(specifier))
+                }
+                // Dynamic imports with expressions, or `import.meta`.
+                (Some(Token::Punct('(' | '.')), _) => {}
+                _ => {
+                    in_statement = true;
+                    // `import type from "..."` imports a default export named `type`.
+                    type_only = next == Some(&Token::Ident("type"))
+                        && !matches!(after_next, Some(Token::Ident("from") | Token::Punct(',')));
+                }
+            },
+            Token::Ident("export") => {
+                in_statement = true;
+                type_only = next == Some(&Token::Ident("type"));
+            }
+            Token::Ident("from") if in_statement => {
+                if let Some(Token::Str(specifier)) = next {
+                    specifiers.push(Import {
+                        specifier,
+                        type_only,
+                    });
+                    in_statement = false;
+                }
+            }
+            Token::Ident("require") => match (next, after_next) {
+                (Some(Token::Punct('(')), Some(Token::Str(specifier))) => {
+                    specifiers.push(Import::runtime(specifier))
+                }
+                // `require.resolve("...")`
+                (Some(Token::Punct('.')), Some(Token::Ident("resolve"))) => {
+                    if let (Some(Token::Punct('(')), Some(Token::Str(specifier))) =
+                        (tokens.get(index + 3), tokens.get(index + 4))
+                    {
+                        specifiers.push(Import::runtime(specifier));
+                    }
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    specifiers
+}
+
+/// The name of the package an import specifier points to, or `None` for
+/// relative imports, subpath imports (`#internal`), protocols (`node:fs`) and
+/// path aliases that can't be package names (`@/utils`, `~/utils`).
+pub fn package_name(specifier: &str) -> Option<&str> {
+    let specifier = specifier.split(['?', '#']).next()?;
+
+    if specifier.contains(':') {
+        return None;
+    }
+
+    let mut segments = specifier.split('/');
+    let first = segments.next()?;
+
+    let (name, unscoped) = match first.strip_prefix('@') {
+        Some(scope) => {
+            let name = segments.next()?;
+
+            if !is_valid_name(scope) {
+                return None;
+            }
+
+            (&specifier[..first.len() + 1 + name.len()], name)
+        }
+        None => (first, first),
+    };
+
+    match is_valid_name(unscoped) {
+        true => Some(name),
+        false => None,
+    }
+}
+
+/// Whether this is a valid npm package name (or scope), excluding legacy
+/// uppercase names.
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(['.', '_'])
+        && name.bytes().all(|byte| {
+            byte.is_ascii_lowercase() || byte.is_ascii_digit() || matches!(byte, b'-' | b'.' | b'_')
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn imports() {
+        let source = r#"
+import React from "react";
+import { useState } from 'react';
+import type { Config } from "tailwindcss";
+import * as path from "node:path";
+import "./styles.css";
+import "@fontsource/inter";
+import def, {
+  a,
+  b,
+} from "@scope/pkg/subpath";
+export { default } from "./local";
+export * from "zod";
+export type { Schema } from "valibot";
+import type from "type-default";
+const lodash = require("lodash");
+const resolved = require.resolve("@babel/core");
+const lazy = await import("next/dynamic");
+const dynamic = await import(`./locales/${locale}.json`);
+import x = require("ts-require");
+"#;
+
+        let imports = parse_imports(source);
+
+        assert_eq!(
+            imports
+                .iter()
+                .map(|import| import.specifier)
+                .collect::<Vec<_>>(),
+            vec![
+                "react",
+                "react",
+                "tailwindcss",
+                "node:path",
+                "./styles.css",
+                "@fontsource/inter",
+                "@scope/pkg/subpath",
+                "./local",
+                "zod",
+                "valibot",
+                "type-default",
+                "lodash",
+                "@babel/core",
+                "next/dynamic",
+                "ts-require",
+            ]
+        );
+        assert_eq!(
+            imports
+                .iter()
+                .filter(|import| import.type_only)
+                .map(|import| import.specifier)
+                .collect::<Vec<_>>(),
+            vec!["tailwindcss", "valibot"]
+        );
+    }
+
+    #[test]
+    fn ignored() {
+        let source = r#"
+// import "commented";
+/* import "block"; require("block"); */
+const message = "import 'string'";
+const template = `require("template")`;
+const regex = /["']import/;
+const object = { from: "not-an-import", import: "nor-this" };
+foo.import("method");
+module.require("method");
+const text = <p>Don't import "jsx"</p>;
+import "after-jsx";
+"#;
+
+        assert_eq!(parse_imports(source), vec![Import::runtime("after-jsx")]);
+    }
+
+    #[test]
+    fn package_names() {
+        assert_eq!(package_name("react"), Some("react"));
+        assert_eq!(package_name("react-dom/client"), Some("react-dom"));
+        assert_eq!(package_name("@scope/pkg"), Some("@scope/pkg"));
+        assert_eq!(package_name("@scope/pkg/sub/path"), Some("@scope/pkg"));
+        assert_eq!(package_name("lodash.merge"), Some("lodash.merge"));
+        assert_eq!(package_name("virtual:pwa"), None);
+        assert_eq!(package_name("node:fs"), None);
+        assert_eq!(package_name("./local"), None);
+        assert_eq!(package_name("../local"), None);
+        assert_eq!(package_name("/absolute"), None);
+        assert_eq!(package_name("#internal"), None);
+        assert_eq!(package_name("@/components"), None);
+        assert_eq!(package_name("~/components"), None);
+        assert_eq!(package_name("$lib/utils"), None);
+        assert_eq!(package_name("@scope"), None);
+        assert_eq!(package_name("raw-loader?inline"), Some("raw-loader"));
+    }
+}
@@ -0,0 +1,81 @@
+use semver::Version;
+use serde::Deserialize;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+#[derive(Deserialize, Debug)]
+pub struct InstalledPackage {
+    pub version: Option<String>,
+    types: Option<String>,
+    typings: Option<String>,
+    exports: Option<serde_json::Value>,
+}
+
+impl InstalledPackage {
+    pub fn read(path: &Path) -> Option<Self> {
+        let value = fs::read_to_string(path).ok()?;
+
+        serde_json::from_str(&value).ok()
+    }
+
+    /// Whether the package ships its own types, either declared in `package.json`
+    /// or with an `index.d.ts` file that TypeScript picks up by default.
+    pub fn has_types(&self, directory: &Path) -> bool {
+        fn has_types_condition(value: &serde_json::Value) -> bool {
+            match value {
+                serde_json::Value::Object(values) => values
+                    .iter()
+                    .any(|(key, value)| key == "types" || has_types_condition(value)),
+                serde_json::Value::Array(values) => values.iter().any(has_types_condition),
+                _ => false,
+            }
+        }
+
+        self.types.is_some()
+            || self.typings.is_some()
+            || self.exports.as_ref().is_some_and(has_types_condition)
+            || directory.join("index.d.ts").is_file()
+    }
+
+    pub fn get_version(&self) -> Option<Version> {
+        self.version
+            .as_ref()
+            .and_then(|version| Version::parse(version).ok())
+    }
+}
+
+/// Find the `package.json` of an installed dependency using the Node.js
+/// resolution algorithm, from the package directory up to the monorepo root.
+/// Symlinks created by pnpm or Yarn are followed when reading the file.
+pub fn find_installed(package_path: &Path, root: &Path, name: &str) -> Option<PathBuf> {
+    let mut directory = Some(package_path);
+
+    while let Some(current) = directory {
+        if !current.starts_with(root) {
+            break;
+        }
+
+        let path = current.join("node_modules").join(name).join("package.json");
+
+        if path.is_file() {
+            return Some(path);
+        }
+
+        directory = current.parent();
+    }
+
+    None
+}
+
+pub fn read_installed(package_path: &Path, root: &Path, name: &str) -> Option<InstalledPackage> {
+    let path = find_installed(package_path, root, name)?;
+
+    InstalledPackage::read(&path)
+}
+
+/// Yarn Plug'n'Play installs don't use `node_modules`.
+pub fn is_plug_n_play(root: &Path) -> bool {
+    root.join(".pnp.cjs").is_file() || root.join(".pnp.js").is_file()
+}
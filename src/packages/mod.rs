@@ -1,25 +1,61 @@
-use self::semversion::SemVersion;
+use self::{
+    imports::NODE_BUILTINS,
+    semversion::{SemRange, SemVersion},
+    sources::Imports,
+};
 use crate::{
     args::{Args, AutofixSelect},
+    lockfile::{Lockfile, LockfileKind, Specifiers},
     rules::{
+        banned_dependencies::BannedDependencyIssue,
+        dependency_range_style::{DependencyRangeStyleIssue, RangeStyle},
         empty_dependencies::{DependencyKind, EmptyDependenciesIssue},
+        installed_dependencies::InstalledDependenciesIssue,
+        invalid_exports::InvalidExportsIssue,
+        invalid_overrides::InvalidOverridesIssue,
+        invalid_package_manager::{InvalidPackageManagerIssue, PackageManagerProblem},
+        invalid_types_packages::{InvalidTypesPackagesIssue, TypesPackage, TypesProblem},
+        missing_dependencies::{MissingDependenciesIssue, MissingDependency},
+        non_existant_entrypoints::NonExistantEntrypointsIssue,
+        outdated_lockfile::OutdatedLockfileIssue,
+        package_files_field::PackageFilesFieldIssue,
+        package_license_field::PackageLicenseFieldIssue,
+        package_repository_field::PackageRepositoryFieldIssue,
+        package_version_field::PackageVersionFieldIssue,
         unordered_dependencies::UnorderedDependenciesIssue,
+        unordered_package_keys::{sort_keys, UnorderedPackageKeysIssue, DEFAULT_KEY_ORDER},
+        unsync_similar_dependencies::SimilarDependency,
+        unused_dependencies::{UnusedDependenciesIssue, DEFAULT_ALLOWED},
         BoxIssue,
     },
 };
 use anyhow::{anyhow, Result};
 use indexmap::IndexMap;
+use installed::InstalledPackage;
+use overrides::{Override, OverridesField};
 use root::RootPackage;
+use semver::Version;
 use serde::Deserialize;
-use std::{fs, path::PathBuf};
+use std::{
+    collections::HashSet,
+    fs,
+    path::{Path, PathBuf},
+};
 
+pub mod exports;
+pub mod imports;
+pub mod installed;
+pub mod overrides;
 pub mod root;
 pub mod semversion;
+pub mod sources;
 
 pub struct PackagesList {
     pub root_package: RootPackage,
     pub packages: Vec<Package>,
     pub packages_issues: Vec<BoxIssue>,
+    /// Folders with their own workspace, linted separately.
+    pub nested_workspaces: Vec<PathBuf>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -39,12 +75,177 @@ pub struct Config {
     pub select: Option<AutofixSelect>,
     pub no_install: bool,
     pub fail_on_warnings: bool,
+    pub non_interactive: bool,
     pub ignore_dependency: Vec<String>,
     pub ignore_package: Vec<String>,
     pub ignore_rule: Vec<String>,
+    pub publish: Option<PublishConfig>,
+    pub engines_strictness: EnginesStrictness,
+    pub dependency_range_style: Vec<RangeStyleConfig>,
+    pub banned_dependencies: Vec<BannedDependencyConfig>,
+    pub check_installed: bool,
+    pub resolved_versions: Option<ResolvedVersions>,
+    pub unused_dependencies: Option<SourcesRuleConfig>,
+    pub missing_dependencies: Option<SourcesRuleConfig>,
+    pub key_order: Option<KeyOrderConfig>,
+    pub similar_dependencies: SimilarDependenciesConfig,
+    pub nested_workspaces: NestedWorkspacesConfig,
+}
+
+/// How nested workspaces (e.g. `examples/` with its own `pnpm-workspace.yaml`)
+/// are linted, in addition to being linted on their own.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct NestedWorkspacesConfig {
+    /// Report dependencies using different versions across workspaces.
+    pub cross_workspace: bool,
+}
+
+/// Groups of dependencies checked by `unsync-similar-dependencies`.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SimilarDependenciesConfig {
+    /// Extra groups, which take precedence over the built-in ones.
+    pub groups: Vec<SimilarDependency>,
+    /// Names of built-in groups to disable, or `*` to disable all of them.
+    pub disable_builtins: Vec<String>,
+    /// Compare versions across all packages of the workspace, instead of
+    /// within each package.
+    pub workspace: bool,
+}
+
+impl SimilarDependenciesConfig {
+    pub fn get_groups(&self) -> Vec<SimilarDependency> {
+        let builtins = SimilarDependency::builtins().into_iter().filter(|builtin| {
+            !self
+                .disable_builtins
+                .iter()
+                .any(|disabled| matches_dependency(disabled, &builtin.name))
+        });
+
+        self.groups.iter().cloned().chain(builtins).collect()
+    }
+}
+
+/// Enables `unordered-package-keys`.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct KeyOrderConfig {
+    /// A custom order of top-level keys, replacing the default one.
+    pub order: Vec<String>,
+}
+
+impl KeyOrderConfig {
+    pub fn get_order(&self) -> Vec<String> {
+        match self.order.is_empty() {
+            true => DEFAULT_KEY_ORDER.map(String::from).to_vec(),
+            false => self.order.clone(),
+        }
+    }
+}
+
+/// Enables a rule scanning source files, i.e. `unused-dependencies` and
+/// `missing-dependencies`.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SourcesRuleConfig {
+    /// Dependencies to skip, e.g. CLIs or config-only tools that are used without
+    /// being imported, or path aliases that look like packages.
+    pub allow: Vec<String>,
+}
+
+/// A dependency that can't be used, optionally only for some versions, or
+/// only allowed in some packages.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BannedDependencyConfig {
+    pub name: String,
+    pub version: Option<String>,
+    pub message: Option<String>,
+    #[serde(default)]
+    pub allowed_in: Vec<String>,
+}
+
+/// The range style to enforce for dependencies of packages matching `packages`
+/// (or all packages when empty). The first matching entry defining a style
+/// for a given dependency kind wins.
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RangeStyleConfig {
+    pub packages: Vec<String>,
+    pub style: Option<RangeStyle>,
+    pub dependencies: Option<RangeStyle>,
+    pub dev_dependencies: Option<RangeStyle>,
+    pub peer_dependencies: Option<RangeStyle>,
+    pub optional_dependencies: Option<RangeStyle>,
+}
+
+/// How `engines` ranges of packages are compared to the root's, or to each other's.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum EnginesStrictness {
+    /// Ranges should have at least one version in common.
+    #[default]
+    Compatible,
+    /// Ranges should be identical.
+    Exact,
+}
+
+/// Which dependencies are checked for multiple versions resolved in the
+/// lockfile, enabled by adding a `resolvedVersions` field to the config.
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ResolvedVersions {
+    /// Only the dependencies declared by each package.
+    Direct,
+    /// All dependencies, including the dependencies of dependencies.
+    Transitive,
+}
+
+/// Checks to run on non-private packages, enabled by adding a `publish` field to the config.
+#[derive(Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PublishConfig {
+    pub version: bool,
+    pub license: bool,
+    pub repository: bool,
+    pub files: bool,
+    pub entrypoints: bool,
+}
+
+impl Default for PublishConfig {
+    fn default() -> Self {
+        Self {
+            version: true,
+            license: true,
+            repository: true,
+            files: true,
+            entrypoints: true,
+        }
+    }
 }
 
 impl Config {
+    pub fn get_range_style(
+        &self,
+        package: &Package,
+        dependency_kind: &DependencyKind,
+    ) -> Option<RangeStyle> {
+        self.dependency_range_style
+            .iter()
+            .filter(|config| config.packages.is_empty() || package.matches(&config.packages))
+            .find_map(|config| {
+                let style = match dependency_kind {
+                    DependencyKind::Dependencies => config.dependencies,
+                    DependencyKind::DevDependencies => config.dev_dependencies,
+                    DependencyKind::PeerDependencies => config.peer_dependencies,
+                    DependencyKind::OptionalDependencies => config.optional_dependencies,
+                };
+
+                style.or(config.style)
+            })
+    }
+
     pub fn merge(&mut self, args: Args) {
         if args.fix {
             self.fix = true;
@@ -62,6 +263,10 @@ impl Config {
             self.fail_on_warnings = true;
         }
 
+        if args.non_interactive {
+            self.non_interactive = true;
+        }
+
         if !args.ignore_dependency.is_empty() {
             self.ignore_dependency.extend(args.ignore_dependency);
         }
@@ -78,13 +283,12 @@ impl Config {
 
 #[derive(Deserialize, Debug)]
 struct DevEngineDependency {
-    #[allow(dead_code)]
     name: String,
+    version: Option<String>,
 }
 
 #[derive(Deserialize, Debug)]
 #[serde(untagged)]
-#[allow(dead_code)]
 enum DevEngineField {
     Single(DevEngineDependency),
     Multiple(Vec<DevEngineDependency>),
@@ -100,14 +304,28 @@ struct DevEngines {
 #[serde(rename_all = "camelCase")]
 struct PackageInner {
     name: Option<String>,
+    version: Option<String>,
     private: Option<bool>,
+    license: Option<serde_json::Value>,
+    repository: Option<serde_json::Value>,
+    files: Option<serde_json::Value>,
+    main: Option<serde_json::Value>,
+    module: Option<serde_json::Value>,
+    types: Option<serde_json::Value>,
+    typings: Option<serde_json::Value>,
+    exports: Option<serde_json::Value>,
     workspaces: Option<Workspaces>,
     package_manager: Option<String>,
     dev_engines: Option<DevEngines>,
+    engines: Option<serde_json::Value>,
     dependencies: Option<IndexMap<String, String>>,
     dev_dependencies: Option<IndexMap<String, String>>,
     peer_dependencies: Option<IndexMap<String, String>>,
     optional_dependencies: Option<IndexMap<String, String>>,
+    scripts: Option<serde_json::Value>,
+    overrides: Option<serde_json::Value>,
+    resolutions: Option<serde_json::Value>,
+    pnpm: Option<serde_json::Value>,
     sherif: Option<Config>,
 }
 
@@ -145,6 +363,10 @@ impl Package {
         &self.inner.name
     }
 
+    pub fn has_workspaces(&self) -> bool {
+        self.inner.workspaces.is_some()
+    }
+
     pub fn get_path(&self) -> String {
         self.path.to_string_lossy().to_string()
     }
@@ -153,6 +375,18 @@ impl Package {
         self.inner.private.unwrap_or(false)
     }
 
+    /// The `engines` ranges, ignoring the legacy array form and non-string ranges.
+    pub fn get_engines(&self) -> Option<IndexMap<String, String>> {
+        let engines = self.inner.engines.as_ref()?.as_object()?;
+
+        Some(
+            engines
+                .iter()
+                .filter_map(|(engine, range)| Some((engine.clone(), range.as_str()?.to_string())))
+                .collect(),
+        )
+    }
+
     pub fn has_package_manager(&self) -> bool {
         if self.inner.package_manager.is_some() {
             return true;
@@ -164,6 +398,163 @@ impl Package {
         }
     }
 
+    pub fn get_package_manager(&self) -> Option<&String> {
+        self.inner.package_manager.as_ref()
+    }
+
+    /// The package managers allowed by `devEngines.packageManager`, with their
+    /// optional version range.
+    fn get_dev_engines_package_managers(&self) -> Vec<(&String, Option<&String>)> {
+        let dependencies = match self
+            .inner
+            .dev_engines
+            .as_ref()
+            .and_then(|dev_engines| dev_engines.package_manager.as_ref())
+        {
+            Some(DevEngineField::Single(dependency)) => vec![dependency],
+            Some(DevEngineField::Multiple(dependencies)) => dependencies.iter().collect(),
+            None => Vec::new(),
+        };
+
+        dependencies
+            .into_iter()
+            .map(|dependency| (&dependency.name, dependency.version.as_ref()))
+            .collect()
+    }
+
+    /// Workspace packages shouldn't switch to another package manager than the
+    /// root's, which corepack would pick when running commands inside them. The
+    /// version is compared too, since another version of the same package manager
+    /// can resolve differently or write the lockfile in another format.
+    pub fn check_package_manager_conflict(&self, root_package_manager: &str) -> Option<BoxIssue> {
+        match &self.inner.package_manager {
+            Some(package_manager) if package_manager != root_package_manager => {
+                Some(InvalidPackageManagerIssue::new(
+                    package_manager.clone(),
+                    PackageManagerProblem::Root(root_package_manager.to_string()),
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn check_version(&self) -> Option<BoxIssue> {
+        match &self.inner.version {
+            Some(version) => match Version::parse(version) {
+                Ok(_) => None,
+                Err(_) => Some(PackageVersionFieldIssue::new(Some(version.clone()))),
+            },
+            None => Some(PackageVersionFieldIssue::new(None)),
+        }
+    }
+
+    pub fn check_license(&self) -> Option<BoxIssue> {
+        match &self.inner.license {
+            Some(serde_json::Value::String(license)) if !license.is_empty() => None,
+            Some(serde_json::Value::Object(_)) => None,
+            _ => Some(PackageLicenseFieldIssue::new()),
+        }
+    }
+
+    pub fn check_repository(&self, root: &Path) -> Option<BoxIssue> {
+        let directory = self
+            .path
+            .strip_prefix(root)
+            .unwrap_or(&self.path)
+            .to_string_lossy()
+            .replace('\\', "/");
+
+        let repository = match &self.inner.repository {
+            Some(repository) => repository,
+            None => return Some(PackageRepositoryFieldIssue::new(directory, false)),
+        };
+
+        match repository
+            .get("directory")
+            .and_then(|current| current.as_str())
+        {
+            Some(current)
+                if current
+                    .replace('\\', "/")
+                    .trim_start_matches("./")
+                    .trim_end_matches('/')
+                    == directory =>
+            {
+                None
+            }
+            _ => Some(PackageRepositoryFieldIssue::new(directory, true)),
+        }
+    }
+
+    pub fn check_files(&self) -> Option<BoxIssue> {
+        match &self.inner.files {
+            Some(serde_json::Value::Array(files)) if files.iter().all(|file| file.is_string()) => {
+                None
+            }
+            Some(files) => Some(PackageFilesFieldIssue::new(Some(files.to_string()))),
+            None if self.path.join(".npmignore").is_file() => None,
+            None => Some(PackageFilesFieldIssue::new(None)),
+        }
+    }
+
+    pub fn check_entrypoints(&self) -> Option<BoxIssue> {
+        let mut entrypoints = Vec::new();
+
+        for (field, value) in [
+            ("main", &self.inner.main),
+            ("module", &self.inner.module),
+            ("types", &self.inner.types),
+            ("typings", &self.inner.typings),
+        ] {
+            if let Some(value) = value {
+                entrypoints.push((field.to_string(), value.clone()));
+            }
+        }
+
+        let non_existant_entrypoints = entrypoints
+            .into_iter()
+            .filter(|(field, target)| match target {
+                serde_json::Value::String(target) => {
+                    !target.contains('*') && !self.has_file(field, target)
+                }
+                _ => true,
+            })
+            .collect::<Vec<_>>();
+
+        match non_existant_entrypoints.is_empty() {
+            true => None,
+            false => Some(NonExistantEntrypointsIssue::new(non_existant_entrypoints)),
+        }
+    }
+
+    /// Resolve a file the same way Node.js does for the `main` and `module`
+    /// fields, trying the `.js` extension and `index.js` when the path doesn't
+    /// exist, and the same way TypeScript does for `types` and `typings`.
+    fn has_file(&self, field: &str, target: &str) -> bool {
+        let path = self.path.join(target);
+        let (extension, index) = match field {
+            "types" | "typings" => ("d.ts", "index.d.ts"),
+            _ => ("js", "index.js"),
+        };
+
+        path.is_file()
+            || PathBuf::from(format!("{}.{}", path.display(), extension)).is_file()
+            || path.join(index).is_file()
+    }
+
+    /// Check the shape of the `exports` field, and optionally that its targets exist.
+    pub fn check_exports(&self, check_files: bool) -> Option<BoxIssue> {
+        if let Some(exports) = &self.inner.exports {
+            let errors = exports::validate(exports, check_files.then_some(self.path.as_path()));
+
+            if !errors.is_empty() {
+                return Some(InvalidExportsIssue::new(errors));
+            }
+        }
+
+        None
+    }
+
     fn check_deps(
         &self,
         deps: &Option<IndexMap<String, String>>,
@@ -210,6 +601,15 @@ impl Package {
         )
     }
 
+    fn get_raw_deps(&self, dependency_kind: &DependencyKind) -> &Option<IndexMap<String, String>> {
+        match dependency_kind {
+            DependencyKind::Dependencies => &self.inner.dependencies,
+            DependencyKind::DevDependencies => &self.inner.dev_dependencies,
+            DependencyKind::PeerDependencies => &self.inner.peer_dependencies,
+            DependencyKind::OptionalDependencies => &self.inner.optional_dependencies,
+        }
+    }
+
     fn get_deps(
         &self,
         deps: &Option<IndexMap<String, String>>,
@@ -230,6 +630,412 @@ impl Package {
         None
     }
 
+    pub fn check_banned_dependencies(&self, banned: &[BannedDependencyConfig]) -> Vec<BoxIssue> {
+        let mut issues: Vec<BoxIssue> = Vec::new();
+
+        for dependency_kind in DependencyKind::ALL {
+            for (name, version) in self.get_raw_deps(&dependency_kind).iter().flatten() {
+                let banned_dependency = banned.iter().find(|banned_dependency| {
+                    matches_dependency(&banned_dependency.name, name)
+                        && !self.matches(&banned_dependency.allowed_in)
+                        && banned_dependency
+                            .version
+                            .as_ref()
+                            .is_none_or(|banned_version| {
+                                match (SemRange::parse(version), SemRange::parse(banned_version)) {
+                                    (Ok(version), Ok(banned_version)) => {
+                                        version.intersects(&banned_version)
+                                    }
+                                    _ => false,
+                                }
+                            })
+                });
+
+                if let Some(banned_dependency) = banned_dependency {
+                    issues.push(BannedDependencyIssue::new(
+                        name.clone(),
+                        version.clone(),
+                        dependency_kind,
+                        banned_dependency.message.clone(),
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+
+    pub fn check_installed(
+        &self,
+        root: &Path,
+        ignored_dependencies: &[String],
+    ) -> Option<BoxIssue> {
+        let mut dependencies = Vec::new();
+
+        // Peer and optional dependencies might not be installed on purpose.
+        for dependency_kind in [
+            DependencyKind::Dependencies,
+            DependencyKind::DevDependencies,
+        ] {
+            for (name, version) in self.get_raw_deps(&dependency_kind).iter().flatten() {
+                if is_dependency_ignored(ignored_dependencies, name, version) {
+                    continue;
+                }
+
+                match installed::read_installed(&self.path, root, name) {
+                    Some(installed) => {
+                        if let (Ok(range), Some(installed_version)) =
+                            (SemRange::parse(version), installed.get_version())
+                        {
+                            if !range.matches(&installed_version) {
+                                dependencies.push((
+                                    dependency_kind,
+                                    name.clone(),
+                                    version.clone(),
+                                    Some(installed_version.to_string()),
+                                ));
+                            }
+                        }
+                    }
+                    None => {
+                        dependencies.push((dependency_kind, name.clone(), version.clone(), None))
+                    }
+                }
+            }
+        }
+
+        match dependencies.is_empty() {
+            true => None,
+            false => Some(InstalledDependenciesIssue::new(dependencies)),
+        }
+    }
+
+    pub fn check_types_packages(&self, root: &Path) -> Option<BoxIssue> {
+        let mut packages = Vec::new();
+
+        for dependency_kind in [
+            DependencyKind::Dependencies,
+            DependencyKind::DevDependencies,
+        ] {
+            for (name, version) in self.get_raw_deps(&dependency_kind).iter().flatten() {
+                let Some(library) = TypesPackage::library(name) else {
+                    continue;
+                };
+
+                // Libraries that aren't installed can't be checked.
+                let Some(path) = installed::find_installed(&self.path, root, &library) else {
+                    continue;
+                };
+                let Some(installed) = InstalledPackage::read(&path) else {
+                    continue;
+                };
+
+                let problem = match installed.has_types(path.parent().unwrap_or(root)) {
+                    true => TypesProblem::BundledTypes,
+                    false => {
+                        let Some(library_version) = installed.get_version() else {
+                            continue;
+                        };
+
+                        // Prefer the installed version of the types, falling back to the declared one.
+                        let types_major = installed::read_installed(&self.path, root, name)
+                            .and_then(|types| types.get_version())
+                            .map(|types_version| types_version.major)
+                            .or_else(|| {
+                                Version::parse(version.trim_start_matches(['^', '~', '=', 'v']))
+                                    .ok()
+                                    .map(|types_version| types_version.major)
+                            });
+
+                        match types_major {
+                            Some(types_major) if types_major != library_version.major => {
+                                TypesProblem::MajorMismatch {
+                                    library_version: library_version.to_string(),
+                                }
+                            }
+                            _ => continue,
+                        }
+                    }
+                };
+
+                packages.push(TypesPackage {
+                    name: name.clone(),
+                    version: version.clone(),
+                    dependency_kind,
+                    problem,
+                });
+            }
+        }
+
+        match packages.is_empty() {
+            true => None,
+            false => Some(InvalidTypesPackagesIssue::new(packages)),
+        }
+    }
+
+    pub fn check_key_order(&self, order: &[String]) -> Option<BoxIssue> {
+        // Deserializing into `PackageInner` loses the order of keys.
+        let value = fs::read_to_string(self.path.join("package.json")).ok()?;
+        let value = serde_json::from_str::<serde_json::Value>(&value).ok()?;
+        let keys = value
+            .as_object()?
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+
+        keys.iter()
+            .zip(sort_keys(&keys, order))
+            .find(|(key, expected)| **key != *expected)
+            .map(|(key, expected)| -> BoxIssue {
+                UnorderedPackageKeysIssue::new(
+                    expected.to_string(),
+                    key.to_string(),
+                    order.to_vec(),
+                )
+            })
+    }
+
+    pub fn get_overrides(&self) -> Vec<Override> {
+        overrides::parse_overrides(
+            self.inner.overrides.as_ref(),
+            self.inner.resolutions.as_ref(),
+            self.inner
+                .pnpm
+                .as_ref()
+                .and_then(|pnpm| pnpm.get("overrides")),
+        )
+    }
+
+    /// Package managers only read overrides from the root package.json.
+    pub fn check_non_root_overrides(&self) -> Option<BoxIssue> {
+        let fields = [
+            (OverridesField::Npm, self.inner.overrides.is_some()),
+            (OverridesField::Yarn, self.inner.resolutions.is_some()),
+            (
+                OverridesField::Pnpm,
+                self.inner
+                    .pnpm
+                    .as_ref()
+                    .is_some_and(|pnpm| pnpm.get("overrides").is_some()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(field, declared)| declared.then_some(field))
+        .collect::<Vec<_>>();
+
+        match fields.is_empty() {
+            true => None,
+            false => Some(InvalidOverridesIssue::new_non_root(fields)),
+        }
+    }
+
+    /// The path of the package relative to the root, as used by lockfiles.
+    fn get_importer(&self, root: &Path) -> Option<String> {
+        match self.path.strip_prefix(root) {
+            Ok(path) if path.as_os_str().is_empty() => Some(".".to_string()),
+            Ok(path) => Some(path.to_string_lossy().replace('\\', "/")),
+            Err(_) => None,
+        }
+    }
+
+    /// Dependencies as recorded by lockfiles, which don't include peer dependencies.
+    pub fn get_locked_specifiers(&self) -> Specifiers {
+        let mut declared = Specifiers::new();
+
+        for dependency_kind in [
+            DependencyKind::Dependencies,
+            DependencyKind::DevDependencies,
+            DependencyKind::OptionalDependencies,
+        ] {
+            declared.extend(
+                self.get_raw_deps(&dependency_kind)
+                    .clone()
+                    .unwrap_or_default(),
+            );
+        }
+
+        declared
+    }
+
+    pub fn check_lockfile(
+        &self,
+        root: &Path,
+        lockfile_kind: LockfileKind,
+        lockfile: &Lockfile,
+        workspace_packages: &HashSet<String>,
+    ) -> Option<BoxIssue> {
+        let importer = self.get_importer(root)?;
+        let dependencies =
+            lockfile.outdated(&importer, &self.get_locked_specifiers(), workspace_packages);
+
+        match dependencies.is_empty() {
+            true => None,
+            false => Some(OutdatedLockfileIssue::new(lockfile_kind, dependencies)),
+        }
+    }
+
+    pub fn get_imports(&self, root: &Path) -> Imports {
+        sources::collect_imports(&self.path, root)
+    }
+
+    pub fn check_unused_dependencies(
+        &self,
+        imports: &Imports,
+        allowed: &[String],
+    ) -> Option<BoxIssue> {
+        let dependencies = self.inner.dependencies.as_ref()?;
+
+        // CLIs can be used in scripts without being imported, e.g. `prisma generate`.
+        let commands = self
+            .inner
+            .scripts
+            .iter()
+            .filter_map(|scripts| scripts.as_object())
+            .flatten()
+            .filter_map(|(_, script)| script.as_str())
+            .flat_map(|script| script.split(|char: char| char.is_whitespace() || char == ';'))
+            .collect::<HashSet<_>>();
+
+        let dependencies = dependencies
+            .iter()
+            .filter(|(name, _)| {
+                let types_for =
+                    name.strip_prefix("@types/")
+                        .map(|name| match name.split_once("__") {
+                            Some((scope, name)) => format!("@{}/{}", scope, name),
+                            None => name.to_string(),
+                        });
+                let command = name.rsplit('/').next().unwrap_or(name);
+
+                !imports.contains_key(*name)
+                    && !types_for.is_some_and(|name| imports.contains_key(&name))
+                    && !commands.contains(name.as_str())
+                    && !commands.contains(command)
+                    && !DEFAULT_ALLOWED
+                        .iter()
+                        .copied()
+                        .chain(allowed.iter().map(String::as_str))
+                        .any(|pattern| matches_dependency(pattern, name))
+            })
+            .map(|(name, version)| (name.clone(), version.clone()))
+            .collect::<Vec<_>>();
+
+        match dependencies.is_empty() {
+            true => None,
+            false => Some(UnusedDependenciesIssue::new(dependencies)),
+        }
+    }
+
+    /// Report imported packages that aren't declared, using the version declared
+    /// by other packages of the workspace to fix them.
+    pub fn check_missing_dependencies(
+        &self,
+        imports: &Imports,
+        allowed: &[String],
+        workspace_versions: &Specifiers,
+    ) -> Option<BoxIssue> {
+        let declared = |name: &str| {
+            [
+                DependencyKind::Dependencies,
+                DependencyKind::DevDependencies,
+                DependencyKind::PeerDependencies,
+                DependencyKind::OptionalDependencies,
+            ]
+            .iter()
+            .any(|dependency_kind| {
+                self.get_raw_deps(dependency_kind)
+                    .as_ref()
+                    .is_some_and(|dependencies| dependencies.contains_key(name))
+            })
+        };
+
+        let dependencies = imports
+            .iter()
+            .filter(|(name, _)| {
+                !declared(name)
+                    && self.get_name().as_ref() != Some(*name)
+                    && !NODE_BUILTINS.contains(&name.as_str())
+                    && !allowed
+                        .iter()
+                        .any(|pattern| matches_dependency(pattern, name))
+            })
+            .map(|(name, files)| {
+                // Only imported by tests, stories or configs, or only for its types.
+                let (dependency_kind, file) = match files
+                    .iter()
+                    .find(|(file, type_only)| !**type_only && !sources::is_dev_file(file))
+                {
+                    Some((file, _)) => (DependencyKind::Dependencies, Some(file)),
+                    None => (DependencyKind::DevDependencies, files.keys().next()),
+                };
+
+                MissingDependency {
+                    name: name.clone(),
+                    file: file
+                        .and_then(|file| file.strip_prefix(&self.path).ok())
+                        .map(|file| file.to_string_lossy().replace('\\', "/"))
+                        .unwrap_or_default(),
+                    dependency_kind,
+                    version: workspace_versions.get(name).cloned(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        match dependencies.is_empty() {
+            true => None,
+            false => Some(MissingDependenciesIssue::new(dependencies)),
+        }
+    }
+
+    /// The name and version of each dependency this package resolves to in the lockfile.
+    pub fn get_resolved_versions(
+        &self,
+        root: &Path,
+        lockfile: &Lockfile,
+        resolved_versions: ResolvedVersions,
+    ) -> Vec<(String, String)> {
+        let Some(importer) = self.get_importer(root) else {
+            return Vec::new();
+        };
+
+        lockfile
+            .resolved(
+                &importer,
+                &self.get_locked_specifiers(),
+                resolved_versions == ResolvedVersions::Transitive,
+            )
+            .into_iter()
+            .map(|(name, version)| (name.to_string(), version.to_string()))
+            .collect()
+    }
+
+    pub fn check_range_style(
+        &self,
+        dependency_kind: DependencyKind,
+        style: RangeStyle,
+        ignored_dependencies: &[String],
+    ) -> Option<BoxIssue> {
+        let dependencies = self
+            .get_raw_deps(&dependency_kind)
+            .iter()
+            .flatten()
+            .filter(|(name, version)| {
+                RangeStyle::of(version).is_some_and(|current_style| current_style != style)
+                    && !is_dependency_ignored(ignored_dependencies, name, version)
+            })
+            .map(|(name, version)| (name.clone(), version.clone()))
+            .collect::<Vec<_>>();
+
+        match dependencies.is_empty() {
+            true => None,
+            false => Some(DependencyRangeStyleIssue::new(
+                dependency_kind,
+                style,
+                dependencies,
+            )),
+        }
+    }
+
     pub fn get_dependencies(&self) -> Option<IndexMap<String, SemVersion>> {
         self.get_deps(&self.inner.dependencies)
     }
@@ -238,20 +1044,56 @@ impl Package {
         self.get_deps(&self.inner.dev_dependencies)
     }
 
+    pub fn get_optional_dependencies(&self) -> Option<IndexMap<String, SemVersion>> {
+        self.get_deps(&self.inner.optional_dependencies)
+    }
+
+    /// Peer dependencies as raw ranges, which often span multiple majors
+    /// (e.g. `^18.0.0 || ^19.0.0`) and can't be parsed as a `SemVersion`.
+    pub fn get_peer_dependencies(&self) -> Option<&IndexMap<String, String>> {
+        self.inner.peer_dependencies.as_ref()
+    }
+
     pub fn is_ignored(&self, ignored_packages: &[String]) -> bool {
+        self.matches(ignored_packages)
+    }
+
+    /// Whether the package name or path matches one of the given patterns,
+    /// which can end with `*` to match a prefix.
+    pub fn matches(&self, patterns: &[String]) -> bool {
         match self.get_name() {
-            Some(name) => ignored_packages.iter().any(|ignored_package| {
-                match ignored_package.ends_with('*') {
-                    true => {
-                        let ignored_package = ignored_package.trim_end_matches('*');
+            Some(name) => patterns.iter().any(|pattern| match pattern.ends_with('*') {
+                true => {
+                    let pattern = pattern.trim_end_matches('*');
 
-                        name.starts_with(ignored_package)
-                            || self.get_path().starts_with(ignored_package)
-                    }
-                    false => ignored_package == name || ignored_package == &self.get_path(),
+                    name.starts_with(pattern) || self.get_path().starts_with(pattern)
                 }
+                false => pattern == name || pattern == &self.get_path(),
             }),
             None => false,
         }
     }
 }
+
+/// Whether a dependency is ignored by its name (e.g. `*eslint*`), or by its
+/// name and version (e.g. `next@4.5.6`).
+pub fn is_dependency_ignored(ignored_dependencies: &[String], name: &str, version: &str) -> bool {
+    ignored_dependencies.iter().any(|dependency| {
+        matches_dependency(dependency, name) || *dependency == format!("{}@{}", name, version)
+    })
+}
+
+/// Whether a dependency name matches the given pattern, which can start
+/// and/or end with `*` to match a suffix, prefix or substring.
+pub fn matches_dependency(pattern: &str, name: &str) -> bool {
+    if pattern.ends_with('*') {
+        if pattern.starts_with('*') {
+            return name.contains(pattern.trim_start_matches('*').trim_end_matches('*'));
+        }
+        return name.starts_with(pattern.trim_end_matches('*'));
+    } else if pattern.starts_with('*') {
+        return name.ends_with(pattern.trim_start_matches('*'));
+    }
+
+    pattern == name
+}
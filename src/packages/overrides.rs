@@ -0,0 +1,256 @@
+use std::fmt::Display;
+
+/// The fields package managers read overrides from, which are only used in
+/// the root `package.json`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OverridesField {
+    /// npm `overrides`, which can be nested to only apply to some dependencies.
+    Npm,
+    /// Yarn `resolutions`, keyed by a glob-like path of dependencies.
+    Yarn,
+    /// pnpm `pnpm.overrides`, keyed by a `>`-separated path of dependencies.
+    Pnpm,
+}
+
+impl OverridesField {
+    /// The path of the field in `package.json`.
+    pub fn path(&self) -> &'static [&'static str] {
+        match self {
+            OverridesField::Npm => &["overrides"],
+            OverridesField::Yarn => &["resolutions"],
+            OverridesField::Pnpm => &["pnpm", "overrides"],
+        }
+    }
+}
+
+impl Display for OverridesField {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path().join("."))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Override {
+    pub field: OverridesField,
+    /// The key as written in `package.json`, e.g. `react`, `**/react` or `parent>react`.
+    pub key: String,
+    /// The name of the overridden package.
+    pub name: String,
+    pub version: String,
+    /// The versions the override applies to, e.g. `<2` for `foo@<2`.
+    pub range: Option<String>,
+    /// Whether the override applies to all dependencies with this name, and
+    /// not only to the dependencies of some packages.
+    pub top_level: bool,
+}
+
+/// Split a `name@range` selector, where the name can be scoped.
+fn split_selector(selector: &str) -> (String, Option<String>) {
+    match selector.get(1..).and_then(|rest| rest.find('@')) {
+        Some(index) => (
+            selector[..index + 1].to_string(),
+            Some(selector[index + 2..].to_string()),
+        ),
+        None => (selector.to_string(), None),
+    }
+}
+
+/// The package names of a Yarn resolution path, e.g. `**/parent/@scope/name`.
+fn yarn_path(key: &str) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut segments = key.split('/').filter(|segment| *segment != "**");
+
+    while let Some(segment) = segments.next() {
+        match segment.starts_with('@') {
+            true => {
+                if let Some(name) = segments.next() {
+                    names.push(format!("{}/{}", segment, name));
+                }
+            }
+            false => names.push(segment.to_string()),
+        }
+    }
+
+    names
+}
+
+fn parse_npm(key: &str, value: &serde_json::Value, parents: usize, overrides: &mut Vec<Override>) {
+    let (name, range) = split_selector(key);
+
+    match value {
+        serde_json::Value::String(version) => overrides.push(Override {
+            field: OverridesField::Npm,
+            key: key.to_string(),
+            name,
+            version: version.clone(),
+            range,
+            top_level: parents == 0,
+        }),
+        // Nested overrides only apply to the dependencies of this package,
+        // while `.` overrides the package itself.
+        serde_json::Value::Object(values) => {
+            for (child, value) in values {
+                match child.as_str() {
+                    "." => parse_npm(key, value, parents, overrides),
+                    _ => parse_npm(child, value, parents + 1, overrides),
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// String entries of an object, skipping invalid values.
+fn entries(value: Option<&serde_json::Value>) -> impl Iterator<Item = (&String, &String)> {
+    value
+        .and_then(|value| value.as_object())
+        .into_iter()
+        .flatten()
+        .filter_map(|(key, value)| match value {
+            serde_json::Value::String(version) => Some((key, version)),
+            _ => None,
+        })
+}
+
+/// Read the overrides of all package managers.
+pub fn parse_overrides(
+    npm: Option<&serde_json::Value>,
+    yarn: Option<&serde_json::Value>,
+    pnpm: Option<&serde_json::Value>,
+) -> Vec<Override> {
+    let mut overrides = Vec::new();
+
+    for (key, value) in npm.and_then(|npm| npm.as_object()).into_iter().flatten() {
+        parse_npm(key, value, 0, &mut overrides);
+    }
+
+    for (key, version) in entries(yarn) {
+        let path = yarn_path(key);
+
+        if let Some(last) = path.last() {
+            let (name, range) = split_selector(last);
+
+            overrides.push(Override {
+                field: OverridesField::Yarn,
+                key: key.clone(),
+                name,
+                version: version.clone(),
+                range,
+                top_level: path.len() == 1,
+            });
+        }
+    }
+
+    for (key, version) in entries(pnpm) {
+        let path = key.split('>').collect::<Vec<_>>();
+
+        if let Some(last) = path.last() {
+            let (name, range) = split_selector(last.trim());
+
+            overrides.push(Override {
+                field: OverridesField::Pnpm,
+                key: key.clone(),
+                name,
+                version: version.clone(),
+                range,
+                top_level: path.len() == 1,
+            });
+        }
+    }
+
+    overrides
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn npm() {
+        let npm = serde_json::from_str::<serde_json::Value>(
+            r#"{
+  "react": "18.2.0",
+  "@scope/pkg@^1": "1.2.0",
+  "parent": {
+    ".": "2.0.0",
+    "child": "3.0.0"
+  }
+}"#,
+        )
+        .unwrap();
+
+        let overrides = parse_overrides(Some(&npm), None, None)
+            .into_iter()
+            .map(|item| (item.name, item.version, item.top_level))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            overrides,
+            vec![
+                ("react".into(), "18.2.0".into(), true),
+                ("@scope/pkg".into(), "1.2.0".into(), true),
+                ("parent".into(), "2.0.0".into(), true),
+                ("child".into(), "3.0.0".into(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn yarn() {
+        let yarn = serde_json::from_str::<serde_json::Value>(
+            r#"{
+  "react": "18.2.0",
+  "**/lodash": "4.17.21",
+  "parent/child": "1.0.0",
+  "@scope/parent/**/@scope/child": "2.0.0"
+}"#,
+        )
+        .unwrap();
+
+        let overrides = parse_overrides(None, Some(&yarn), None)
+            .into_iter()
+            .map(|item| (item.name, item.top_level))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            overrides,
+            vec![
+                ("react".into(), true),
+                ("lodash".into(), true),
+                ("child".into(), false),
+                ("@scope/child".into(), false),
+            ]
+        );
+    }
+
+    #[test]
+    fn pnpm() {
+        let pnpm = serde_json::from_str::<serde_json::Value>(
+            r#"{
+  "react": "18.2.0",
+  "foo@<2": "1.5.0",
+  "parent@1>@scope/child": "2.0.0"
+}"#,
+        )
+        .unwrap();
+
+        let overrides = parse_overrides(None, None, Some(&pnpm))
+            .into_iter()
+            .map(|item| (item.key, item.name, item.range, item.top_level))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            overrides,
+            vec![
+                ("react".into(), "react".into(), None, true),
+                ("foo@<2".into(), "foo".into(), Some("<2".into()), true),
+                (
+                    "parent@1>@scope/child".into(),
+                    "@scope/child".into(),
+                    None,
+                    false
+                ),
+            ]
+        );
+    }
+}
@@ -1,15 +1,28 @@
-use super::{semversion::SemVersion, Package, Workspaces};
+use super::{
+    semversion::{SemRange, SemVersion},
+    Package, Workspaces,
+};
 use crate::{
-    packages::Config,
+    lockfile::{Lockfile, LockfileKind, Specifiers},
+    packages::{BannedDependencyConfig, Config, ResolvedVersions},
     rules::{
+        dependency_range_style::RangeStyle,
+        empty_dependencies::DependencyKind,
+        invalid_overrides::InvalidOverridesIssue,
+        invalid_package_manager::{
+            InvalidPackageManagerIssue, PackageManagerProblem, KNOWN_PACKAGE_MANAGERS,
+        },
         root_package_dependencies::RootPackageDependenciesIssue,
         root_package_manager_field::RootPackageManagerFieldIssue,
-        root_package_private_field::RootPackagePrivateFieldIssue, BoxIssue,
+        root_package_private_field::RootPackagePrivateFieldIssue,
+        unused_overrides::UnusedOverridesIssue,
+        BoxIssue,
     },
 };
 use anyhow::Result;
 use indexmap::IndexMap;
-use std::path::Path;
+use semver::Version;
+use std::{collections::HashSet, path::Path};
 
 #[derive(Debug)]
 pub struct RootPackage(Package);
@@ -54,6 +67,132 @@ impl RootPackage {
         }
     }
 
+    pub fn get_package_manager(&self) -> Option<&String> {
+        self.0.get_package_manager()
+    }
+
+    /// Validate the format of the `packageManager` field, and that it agrees
+    /// with `devEngines.packageManager` and the lockfile at the root.
+    pub fn check_package_manager_value(&self, root: &Path) -> Option<BoxIssue> {
+        let value = self.0.get_package_manager()?;
+        let issue = |problem| -> Option<BoxIssue> {
+            Some(InvalidPackageManagerIssue::new(value.clone(), problem))
+        };
+
+        let (name, version) = match parse_package_manager(value) {
+            Ok(package_manager) => package_manager,
+            Err(problem) => return issue(problem),
+        };
+
+        let dev_engines = self.0.get_dev_engines_package_managers();
+
+        if !dev_engines.is_empty()
+            && !dev_engines.iter().any(|(dev_engine, range)| {
+                *dev_engine == name
+                    && range.is_none_or(|range| {
+                        SemRange::parse(range).is_ok_and(|range| range.matches(&version))
+                    })
+            })
+        {
+            return issue(PackageManagerProblem::DevEngines(
+                dev_engines
+                    .into_iter()
+                    .map(|(dev_engine, range)| match range {
+                        Some(range) => format!("{}@{}", dev_engine, range),
+                        None => dev_engine.clone(),
+                    })
+                    .collect(),
+            ));
+        }
+
+        let lockfiles = LockfileKind::ALL
+            .into_iter()
+            .filter(|kind| root.join(kind.file_name()).is_file())
+            .collect::<Vec<_>>();
+
+        match lockfiles.first() {
+            Some(lockfile_kind)
+                if !lockfiles
+                    .iter()
+                    .any(|lockfile_kind| lockfile_kind.package_manager() == name) =>
+            {
+                issue(PackageManagerProblem::Lockfile(*lockfile_kind))
+            }
+            _ => None,
+        }
+    }
+
+    /// Top-level overrides should accept the ranges declared in the workspace,
+    /// unless their selector (e.g. `foo@<2`) excludes them.
+    pub fn check_overrides(
+        &self,
+        declared_dependencies: &IndexMap<String, Vec<(String, String)>>,
+    ) -> Option<BoxIssue> {
+        let mut conflicts = Vec::new();
+
+        for item in self.0.get_overrides() {
+            let Some(declared) = declared_dependencies.get(&item.name) else {
+                continue;
+            };
+
+            // References (`$react`), aliases and removals (`-`) aren't versions.
+            let (true, Ok(version)) = (item.top_level, SemRange::parse(&item.version)) else {
+                continue;
+            };
+            let selector = item
+                .range
+                .as_ref()
+                .and_then(|range| SemRange::parse(range).ok());
+
+            let declared = declared
+                .iter()
+                .filter(|(_, range)| match SemRange::parse(range) {
+                    Ok(range) => {
+                        selector
+                            .as_ref()
+                            .is_none_or(|selector| selector.intersects(&range))
+                            && !version.intersects(&range)
+                    }
+                    Err(_) => false,
+                })
+                .cloned()
+                .collect::<Vec<_>>();
+
+            if !declared.is_empty() {
+                conflicts.push((item, declared));
+            }
+        }
+
+        match conflicts.is_empty() {
+            true => None,
+            false => Some(InvalidOverridesIssue::new(conflicts)),
+        }
+    }
+
+    /// Overrides targeting dependencies that are neither declared nor resolved
+    /// in the lockfile, which is needed to know about transitive dependencies.
+    pub fn check_unused_overrides(
+        &self,
+        declared_dependencies: &IndexMap<String, Vec<(String, String)>>,
+        locked_dependencies: &HashSet<&str>,
+    ) -> Option<BoxIssue> {
+        let overrides = self
+            .0
+            .get_overrides()
+            .into_iter()
+            .filter(|item| {
+                item.top_level
+                    && !declared_dependencies.contains_key(&item.name)
+                    && !locked_dependencies.contains(item.name.as_str())
+            })
+            .collect::<Vec<_>>();
+
+        match overrides.is_empty() {
+            true => None,
+            false => Some(UnusedOverridesIssue::new(overrides)),
+        }
+    }
+
     pub fn check_dependencies(&self) -> Option<BoxIssue> {
         match self.0.inner.dependencies.is_some() {
             true => Some(RootPackageDependenciesIssue::new()),
@@ -73,6 +212,69 @@ impl RootPackage {
         self.0.check_optional_dependencies()
     }
 
+    pub fn check_banned_dependencies(&self, banned: &[BannedDependencyConfig]) -> Vec<BoxIssue> {
+        self.0.check_banned_dependencies(banned)
+    }
+
+    pub fn check_installed(
+        &self,
+        root: &Path,
+        ignored_dependencies: &[String],
+    ) -> Option<BoxIssue> {
+        self.0.check_installed(root, ignored_dependencies)
+    }
+
+    pub fn check_key_order(&self, order: &[String]) -> Option<BoxIssue> {
+        self.0.check_key_order(order)
+    }
+
+    pub fn check_types_packages(&self, root: &Path) -> Option<BoxIssue> {
+        self.0.check_types_packages(root)
+    }
+
+    pub fn check_lockfile(
+        &self,
+        root: &Path,
+        lockfile_kind: LockfileKind,
+        lockfile: &Lockfile,
+        workspace_packages: &HashSet<String>,
+    ) -> Option<BoxIssue> {
+        self.0
+            .check_lockfile(root, lockfile_kind, lockfile, workspace_packages)
+    }
+
+    pub fn get_locked_specifiers(&self) -> Specifiers {
+        self.0.get_locked_specifiers()
+    }
+
+    pub fn get_resolved_versions(
+        &self,
+        root: &Path,
+        lockfile: &Lockfile,
+        resolved_versions: ResolvedVersions,
+    ) -> Vec<(String, String)> {
+        self.0
+            .get_resolved_versions(root, lockfile, resolved_versions)
+    }
+
+    pub fn check_range_style(
+        &self,
+        dependency_kind: DependencyKind,
+        style: RangeStyle,
+        ignored_dependencies: &[String],
+    ) -> Option<BoxIssue> {
+        self.0
+            .check_range_style(dependency_kind, style, ignored_dependencies)
+    }
+
+    pub fn get_range_style(
+        &self,
+        config: &Config,
+        dependency_kind: &DependencyKind,
+    ) -> Option<RangeStyle> {
+        config.get_range_style(&self.0, dependency_kind)
+    }
+
     pub fn get_dependencies(&self) -> Option<IndexMap<String, SemVersion>> {
         self.0.get_dependencies()
     }
@@ -81,7 +283,92 @@ impl RootPackage {
         self.0.get_dev_dependencies()
     }
 
+    pub fn get_optional_dependencies(&self) -> Option<IndexMap<String, SemVersion>> {
+        self.0.get_optional_dependencies()
+    }
+
+    pub fn get_peer_dependencies(&self) -> Option<&IndexMap<String, String>> {
+        self.0.get_peer_dependencies()
+    }
+
+    pub fn get_engines(&self) -> Option<IndexMap<String, String>> {
+        self.0.get_engines()
+    }
+
     pub fn get_config(&self) -> Option<Config> {
         self.0.inner.sherif.clone()
     }
 }
+
+/// Parse a `packageManager` field, following `<name>@<version>[+<hash>]`.
+/// See https://nodejs.org/api/corepack.html#configuring-a-package
+fn parse_package_manager(value: &str) -> Result<(&str, Version), PackageManagerProblem> {
+    let Some((name, version)) = value.split_once('@') else {
+        return Err(PackageManagerProblem::InvalidFormat);
+    };
+
+    if !KNOWN_PACKAGE_MANAGERS.contains(&name) {
+        return Err(PackageManagerProblem::UnknownPackageManager(
+            name.to_string(),
+        ));
+    }
+
+    // The hash is `<algorithm>.<hex digest>`, e.g. `sha512.7f3a...`.
+    let (version, hash) = match version.split_once('+') {
+        Some((version, hash)) => (version, Some(hash)),
+        None => (version, None),
+    };
+
+    let valid_hash = hash.is_none_or(|hash| {
+        hash.split_once('.').is_some_and(|(algorithm, digest)| {
+            !algorithm.is_empty()
+                && algorithm.bytes().all(|byte| byte.is_ascii_alphanumeric())
+                && !digest.is_empty()
+                && digest.bytes().all(|byte| byte.is_ascii_hexdigit())
+        })
+    });
+
+    match (Version::parse(version), valid_hash) {
+        (Ok(version), true) => Ok((name, version)),
+        _ => Err(PackageManagerProblem::InvalidFormat),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn package_manager() {
+        assert_eq!(
+            parse_package_manager("pnpm@9.0.0"),
+            Ok(("pnpm", Version::new(9, 0, 0)))
+        );
+        assert_eq!(
+            parse_package_manager(
+                "yarn@4.1.0+sha224.953c8233f7a92884eee2de69a1b92d1f2ec1655e66d08071ba9a02fa"
+            ),
+            Ok(("yarn", Version::new(4, 1, 0)))
+        );
+        assert_eq!(
+            parse_package_manager("npm@10.0.0-rc.1"),
+            Ok(("npm", Version::parse("10.0.0-rc.1").unwrap()))
+        );
+        assert_eq!(
+            parse_package_manager("pnpm"),
+            Err(PackageManagerProblem::InvalidFormat)
+        );
+        assert_eq!(
+            parse_package_manager("pnpm@^9"),
+            Err(PackageManagerProblem::InvalidFormat)
+        );
+        assert_eq!(
+            parse_package_manager("pnpm@9.0.0+sha512"),
+            Err(PackageManagerProblem::InvalidFormat)
+        );
+        assert_eq!(
+            parse_package_manager("deno@2.0.0"),
+            Err(PackageManagerProblem::UnknownPackageManager("deno".into()))
+        );
+    }
+}
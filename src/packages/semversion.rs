@@ -1,5 +1,5 @@
 use anyhow::{anyhow, Result};
-use semver::{Prerelease, Version, VersionReq};
+use semver::{Comparator, Op, Prerelease, Version, VersionReq};
 use std::{cmp::Ordering, fmt::Display};
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
@@ -96,3 +96,201 @@ impl SemVersion {
         }
     }
 }
+
+/// A npm version range, e.g. `^18 || >=20.0.0 <21`. The `semver` crate only
+/// supports comma-separated comparators, so each `||` part is parsed
+/// separately after normalizing its comparators.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SemRange(Vec<VersionReq>);
+
+impl Display for SemRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ranges = self
+            .0
+            .iter()
+            .map(|range| range.to_string())
+            .collect::<Vec<_>>()
+            .join(" || ");
+
+        f.write_str(&ranges)
+    }
+}
+
+impl SemRange {
+    pub fn parse(range: &str) -> Result<Self> {
+        let mut ranges = Vec::new();
+
+        for range in range.split("||") {
+            let range = range.trim();
+
+            let comparators = match range.split_once(" - ") {
+                // Hyphen ranges, e.g. `1.2.3 - 2.3.4`
+                Some((from, to)) => vec![
+                    format!(">={}", from.trim().trim_start_matches('v')),
+                    format!("<={}", to.trim().trim_start_matches('v')),
+                ],
+                None => {
+                    let mut comparators = Vec::<String>::new();
+                    let mut operator = String::new();
+
+                    for token in range.split_whitespace() {
+                        let version = token.trim_start_matches(['<', '>', '=', '^', '~']);
+                        operator += &token[..token.len() - version.len()];
+
+                        // The operator might be separated from its version, e.g. `>= 1.2.3`
+                        if version.is_empty() {
+                            continue;
+                        }
+
+                        let version = version.trim_start_matches('v');
+
+                        if operator.is_empty() {
+                            // Unlike npm, the `semver` crate treats versions without operator as
+                            // caret ranges, and wildcards don't restrict anything.
+                            match version {
+                                "*" | "x" | "X" => {}
+                                version if version.contains(['*', 'x', 'X']) => {
+                                    comparators.push(version.to_string())
+                                }
+                                version => comparators.push(format!("={}", version)),
+                            }
+                        } else {
+                            comparators.push(format!("{}{}", operator, version));
+                            operator.clear();
+                        }
+                    }
+
+                    comparators
+                }
+            };
+
+            match comparators.is_empty() {
+                true => ranges.push(VersionReq::STAR),
+                false => ranges.push(VersionReq::parse(&comparators.join(", "))?),
+            }
+        }
+
+        Ok(Self(ranges))
+    }
+
+    pub fn matches(&self, version: &Version) -> bool {
+        self.0.iter().any(|range| range.matches(version))
+    }
+
+    /// Whether at least one version satisfies both ranges.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.0.iter().any(|range| {
+            other.0.iter().any(|other| {
+                let (lower, upper) = range
+                    .comparators
+                    .iter()
+                    .chain(other.comparators.iter())
+                    .map(bounds)
+                    .fold((Version::new(0, 0, 0), None), intersect);
+
+                upper.is_none_or(|upper| lower < upper)
+            })
+        })
+    }
+}
+
+type Bounds = (Version, Option<Version>);
+
+fn intersect((lower, upper): Bounds, (other_lower, other_upper): Bounds) -> Bounds {
+    let upper = match (upper, other_upper) {
+        (Some(upper), Some(other_upper)) => Some(upper.min(other_upper)),
+        (upper, other_upper) => upper.or(other_upper),
+    };
+
+    (lower.max(other_lower), upper)
+}
+
+/// The inclusive lower bound and exclusive upper bound of a comparator,
+/// ignoring prereleases.
+fn bounds(comparator: &Comparator) -> Bounds {
+    let major = comparator.major;
+    let minor = comparator.minor.unwrap_or(0);
+    let patch = comparator.patch.unwrap_or(0);
+    let version = Version::new(major, minor, patch);
+
+    // The next version not matching the comparator when ignoring its missing parts,
+    // e.g. `1.3.0` for `1.2` and `2.0.0` for `1`.
+    let next = match (comparator.minor, comparator.patch) {
+        (Some(_), Some(_)) => Version::new(major, minor, patch + 1),
+        (Some(_), None) => Version::new(major, minor + 1, 0),
+        _ => Version::new(major + 1, 0, 0),
+    };
+
+    match comparator.op {
+        Op::Exact | Op::Wildcard => (version, Some(next)),
+        Op::Greater => (next, None),
+        Op::GreaterEq => (version, None),
+        Op::Less => (Version::new(0, 0, 0), Some(version)),
+        Op::LessEq => (Version::new(0, 0, 0), Some(next)),
+        Op::Tilde => match comparator.minor {
+            Some(_) => (version, Some(Version::new(major, minor + 1, 0))),
+            None => (version, Some(next)),
+        },
+        Op::Caret => {
+            let upper = match (major, comparator.minor, comparator.patch) {
+                (0, Some(0), Some(_)) => Version::new(0, 0, patch + 1),
+                (0, Some(_), _) => Version::new(0, minor + 1, 0),
+                _ => Version::new(major + 1, 0, 0),
+            };
+
+            (version, Some(upper))
+        }
+        _ => (Version::new(0, 0, 0), None),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn parse_range() {
+        assert_eq!(SemRange::parse(">=18").unwrap().to_string(), ">=18");
+        assert_eq!(
+            SemRange::parse("^18 || >= 20.0.0 <21").unwrap().to_string(),
+            "^18 || >=20.0.0, <21"
+        );
+        assert_eq!(
+            SemRange::parse("1.2.3 - 2.3.4").unwrap().to_string(),
+            ">=1.2.3, <=2.3.4"
+        );
+        assert_eq!(SemRange::parse("*").unwrap().to_string(), "*");
+        assert_eq!(SemRange::parse("").unwrap().to_string(), "*");
+        assert!(SemRange::parse("latest").is_err());
+    }
+
+    #[test]
+    fn matches_range() {
+        let range = SemRange::parse("^18.0.0 || 20.0.0").unwrap();
+
+        assert!(range.matches(&Version::parse("18.2.0").unwrap()));
+        assert!(range.matches(&Version::parse("20.0.0").unwrap()));
+        assert!(!range.matches(&Version::parse("20.1.0").unwrap()));
+        assert!(!range.matches(&Version::parse("19.0.0").unwrap()));
+    }
+
+    #[test]
+    fn intersects_range() {
+        let intersects = |a: &str, b: &str| {
+            SemRange::parse(a)
+                .unwrap()
+                .intersects(&SemRange::parse(b).unwrap())
+        };
+
+        assert!(intersects(">=18", ">=16"));
+        assert!(intersects(">=18", "^20"));
+        assert!(intersects("^18 || ^20", "~20.1"));
+        assert!(intersects("*", "1.2.3"));
+        assert!(intersects("<=18", "18"));
+        assert!(!intersects(">=18", "<18"));
+        assert!(!intersects("^16", "^18"));
+        assert!(!intersects("^0.2.0", "^0.3.0"));
+        assert!(!intersects(">18.2.3", "18.2.3"));
+        assert!(!intersects("~1.2.3", ">=1.3.0"));
+    }
+}
@@ -0,0 +1,336 @@
+use super::imports::{package_name, parse_imports};
+use indexmap::IndexMap;
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+const SOURCE_EXTENSIONS: [&str; 11] = [
+    "js", "jsx", "mjs", "cjs", "ts", "tsx", "mts", "cts", "vue", "svelte", "astro",
+];
+
+/// A pattern from a `.gitignore` file.
+/// See https://git-scm.com/docs/gitignore#_pattern_format
+#[derive(Debug)]
+struct IgnorePattern {
+    pattern: String,
+    negated: bool,
+    directory_only: bool,
+    /// Whether the pattern matches paths relative to the `.gitignore` file,
+    /// instead of file names at any depth.
+    anchored: bool,
+}
+
+#[derive(Debug)]
+pub(crate) struct Gitignore {
+    directory: PathBuf,
+    patterns: Vec<IgnorePattern>,
+}
+
+impl Gitignore {
+    pub(crate) fn read(directory: &Path) -> Option<Self> {
+        let value = fs::read_to_string(directory.join(".gitignore")).ok()?;
+
+        Some(Self {
+            directory: directory.to_path_buf(),
+            patterns: Self::parse(&value),
+        })
+    }
+
+    fn parse(value: &str) -> Vec<IgnorePattern> {
+        value
+            .lines()
+            .map(|line| line.trim_end())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let (line, negated) = match line.strip_prefix('!') {
+                    Some(line) => (line, true),
+                    None => (line.trim_start_matches('\\'), false),
+                };
+                let (line, directory_only) = match line.strip_suffix('/') {
+                    Some(line) => (line, true),
+                    None => (line, false),
+                };
+
+                IgnorePattern {
+                    pattern: line.trim_start_matches('/').to_string(),
+                    negated,
+                    directory_only,
+                    anchored: line.contains('/'),
+                }
+            })
+            .collect()
+    }
+
+    /// Whether the path is ignored, or `None` if no pattern matches it.
+    fn is_ignored(&self, path: &Path, is_dir: bool) -> Option<bool> {
+        let relative = path.strip_prefix(&self.directory).ok()?;
+        let relative = relative.to_string_lossy().replace('\\', "/");
+        let name = path.file_name()?.to_string_lossy();
+
+        self.patterns
+            .iter()
+            .rev()
+            .find(|pattern| {
+                (is_dir || !pattern.directory_only)
+                    && match pattern.anchored {
+                        true => glob_match(pattern.pattern.as_bytes(), relative.as_bytes()),
+                        false => glob_match(pattern.pattern.as_bytes(), name.as_bytes()),
+                    }
+            })
+            .map(|pattern| !pattern.negated)
+    }
+}
+
+/// Match a gitignore glob, where `*` and `?` don't match `/` but `**` does.
+fn glob_match(pattern: &[u8], text: &[u8]) -> bool {
+    match pattern.first() {
+        None => text.is_empty(),
+        Some(b'*') if pattern.get(1) == Some(&b'*') => {
+            let rest = pattern[2..].strip_prefix(b"/").unwrap_or(&pattern[2..]);
+
+            if rest.is_empty() {
+                return true;
+            }
+
+            (0..=text.len()).any(|index| {
+                (index == 0 || text[index - 1] == b'/') && glob_match(rest, &text[index..])
+            }) || glob_match(rest, text)
+        }
+        Some(b'*') => (0..=text.len())
+            .take_while(|index| *index == 0 || text[index - 1] != b'/')
+            .any(|index| glob_match(&pattern[1..], &text[index..])),
+        Some(b'?') => !text.is_empty() && text[0] != b'/' && glob_match(&pattern[1..], &text[1..]),
+        Some(b'[') => {
+            let Some(end) = pattern.iter().skip(1).position(|byte| *byte == b']') else {
+                return text.first() == Some(&b'[') && glob_match(&pattern[1..], &text[1..]);
+            };
+            let class = &pattern[1..end + 1];
+            let (class, negated) = match class.first() {
+                Some(b'!' | b'^') => (&class[1..], true),
+                _ => (class, false),
+            };
+
+            let Some(byte) = text.first() else {
+                return false;
+            };
+
+            let mut matched = false;
+            let mut index = 0;
+
+            while index < class.len() {
+                if class.get(index + 1) == Some(&b'-') && index + 2 < class.len() {
+                    matched |= (class[index]..=class[index + 2]).contains(byte);
+                    index += 3;
+                } else {
+                    matched |= class[index] == *byte;
+                    index += 1;
+                }
+            }
+
+            matched != negated && glob_match(&pattern[end + 2..], &text[1..])
+        }
+        Some(byte) => text.first() == Some(byte) && glob_match(&pattern[1..], &text[1..]),
+    }
+}
+
+/// Whether a workspace glob (e.g. `packages/*` or `**/test/**`) matches a path
+/// relative to the root, or everything inside it.
+pub(crate) fn matches_workspace_glob(pattern: &str, path: &str) -> bool {
+    glob_match(pattern.as_bytes(), path.as_bytes())
+        || glob_match(pattern.as_bytes(), format!("{}/", path).as_bytes())
+}
+
+/// Whether the path is ignored by the closest `.gitignore` with a matching pattern.
+pub(crate) fn is_ignored(gitignores: &[Gitignore], path: &Path, is_dir: bool) -> bool {
+    gitignores
+        .iter()
+        .rev()
+        .find_map(|gitignore| gitignore.is_ignored(path, is_dir))
+        .unwrap_or(false)
+}
+
+fn walk(directory: &Path, gitignores: &mut Vec<Gitignore>, files: &mut Vec<PathBuf>) {
+    let Ok(entries) = directory.read_dir() else {
+        return;
+    };
+
+    let gitignore = Gitignore::read(directory);
+    let has_gitignore = gitignore.is_some();
+    gitignores.extend(gitignore);
+
+    // Symlinks are skipped, since they can point to a parent directory.
+    let mut entries = entries
+        .flatten()
+        .filter_map(|entry| {
+            let file_type = entry.file_type().ok()?;
+
+            (!file_type.is_symlink()).then(|| (entry.path(), file_type.is_dir()))
+        })
+        .collect::<Vec<_>>();
+    entries.sort();
+
+    for (path, is_dir) in entries {
+        let Some(name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+
+        if is_dir {
+            // Nested packages are scanned on their own.
+            if matches!(name, "node_modules" | ".git")
+                || path.join("package.json").is_file()
+                || is_ignored(gitignores, &path, true)
+            {
+                continue;
+            }
+
+            walk(&path, gitignores, files);
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| SOURCE_EXTENSIONS.contains(&extension))
+            && !name.ends_with(".d.ts")
+            && !is_ignored(gitignores, &path, false)
+        {
+            files.push(path);
+        }
+    }
+
+    if has_gitignore {
+        gitignores.pop();
+    }
+}
+
+/// Whether a file is only used during development, e.g. tests, stories or configs.
+pub fn is_dev_file(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    [".test.", ".spec.", ".stories.", ".config."]
+        .iter()
+        .any(|pattern| name.contains(pattern))
+        || path.components().any(|component| {
+            matches!(
+                component.as_os_str().to_str(),
+                Some("__tests__" | "test" | "tests" | "e2e")
+            )
+        })
+}
+
+/// Imported package names, with the files importing them and whether these
+/// files only import their types.
+pub type Imports = IndexMap<String, IndexMap<PathBuf, bool>>;
+
+/// Scan the source files of a package, respecting `.gitignore` files from the
+/// root of the monorepo, and return the name of each imported package with the
+/// files importing it.
+pub fn collect_imports(package_path: &Path, root: &Path) -> Imports {
+    let mut gitignores = package_path
+        .ancestors()
+        .skip(1)
+        .take_while(|directory| directory.starts_with(root))
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+        .filter_map(Gitignore::read)
+        .collect::<Vec<_>>();
+
+    let mut files = Vec::new();
+    walk(package_path, &mut gitignores, &mut files);
+
+    let mut imports = Imports::new();
+
+    for file in files {
+        let Ok(source) = fs::read_to_string(&file) else {
+            continue;
+        };
+
+        for import in parse_imports(&source) {
+            if let Some(name) = package_name(import.specifier) {
+                let type_only = imports
+                    .entry(name.to_string())
+                    .or_default()
+                    .entry(file.clone())
+                    .or_insert(true);
+
+                *type_only &= import.type_only;
+            }
+        }
+    }
+
+    imports
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn glob() {
+        assert!(glob_match(b"dist", b"dist"));
+        assert!(glob_match(b"*.log", b"debug.log"));
+        assert!(!glob_match(b"*.log", b"logs/debug.log"));
+        assert!(glob_match(b"src/*.ts", b"src/index.ts"));
+        assert!(!glob_match(b"src/*.ts", b"src/nested/index.ts"));
+        assert!(glob_match(b"**/generated", b"src/generated"));
+        assert!(glob_match(b"**/generated", b"generated"));
+        assert!(glob_match(b"src/**/*.gen.ts", b"src/a/b/c.gen.ts"));
+        assert!(glob_match(b"src/**/*.gen.ts", b"src/c.gen.ts"));
+        assert!(glob_match(b"build/**", b"build/index.js"));
+        assert!(glob_match(b"file?.js", b"file1.js"));
+        assert!(glob_match(b"file[0-9].js", b"file1.js"));
+        assert!(!glob_match(b"file[!0-9].js", b"file1.js"));
+    }
+
+    #[test]
+    fn dev_files() {
+        assert!(is_dev_file(Path::new("packages/abc/src/index.test.ts")));
+        assert!(is_dev_file(Path::new("packages/abc/vite.config.ts")));
+        assert!(is_dev_file(Path::new(
+            "packages/abc/src/__tests__/index.ts"
+        )));
+        assert!(is_dev_file(Path::new("packages/abc/e2e/home.ts")));
+        assert!(!is_dev_file(Path::new("packages/abc/src/index.ts")));
+        assert!(!is_dev_file(Path::new("packages/abc/src/testing.ts")));
+    }
+
+    #[test]
+    fn gitignore() {
+        let gitignore = Gitignore {
+            directory: PathBuf::from("root"),
+            patterns: Gitignore::parse(
+                "# Build output\n/dist\nbuild/\n*.generated.ts\n!keep.generated.ts\nsrc/**/fixtures\n",
+            ),
+        };
+
+        let is_ignored = |path: &str, is_dir: bool| gitignore.is_ignored(Path::new(path), is_dir);
+
+        assert_eq!(is_ignored("root/dist", true), Some(true));
+        assert_eq!(is_ignored("root/packages/abc/dist", true), None);
+        assert_eq!(is_ignored("root/packages/abc/build", true), Some(true));
+        assert_eq!(is_ignored("root/build", false), None);
+        assert_eq!(is_ignored("root/src/api.generated.ts", false), Some(true));
+        assert_eq!(is_ignored("root/src/keep.generated.ts", false), Some(false));
+        assert_eq!(is_ignored("root/src/a/fixtures", true), Some(true));
+        assert_eq!(is_ignored("root/src/index.ts", false), None);
+        assert_eq!(is_ignored("other/dist", true), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinks() {
+        let directory = tempfile::tempdir().unwrap();
+        let root = directory.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/index.ts"), "import react from 'react';").unwrap();
+        std::os::unix::fs::symlink(root, root.join("src/parent")).unwrap();
+
+        let imports = collect_imports(root, root);
+        assert_eq!(
+            imports["react"].keys().collect::<Vec<_>>(),
+            vec![&root.join("src/index.ts")]
+        );
+    }
+}
@@ -19,6 +19,11 @@ pub fn print_error(title: &str, message: &str) {
     eprintln!("   {}", message.bright_black());
 }
 
+pub fn print_workspace(title: &str) {
+    println!();
+    println!("{}", format!("{}:", title).bold().underline());
+}
+
 pub fn print_issues(issues: IssuesList) -> Result<()> {
     // Lock stdout manually instead of in every `println`
     // calls, since we might have a lot of them.
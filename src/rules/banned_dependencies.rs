@@ -0,0 +1,101 @@
+use super::{empty_dependencies::DependencyKind, Issue, IssueLevel};
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct BannedDependencyIssue {
+    name: String,
+    version: String,
+    dependency_kind: DependencyKind,
+    reason: Option<String>,
+}
+
+impl BannedDependencyIssue {
+    pub fn new(
+        name: String,
+        version: String,
+        dependency_kind: DependencyKind,
+        reason: Option<String>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            name,
+            version,
+            dependency_kind,
+            reason,
+        })
+    }
+}
+
+impl Issue for BannedDependencyIssue {
+    fn name(&self) -> &str {
+        "banned-dependencies"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        format!(
+            r#"  │ {{
+  │   "{}": {{
+  {}     "{}": "{}"   {}
+  │   }}
+  │ }}"#,
+            self.dependency_kind.to_string().white(),
+            "-".red(),
+            self.name.white(),
+            self.version.white(),
+            "← this dependency is banned.".red(),
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        match &self.reason {
+            Some(reason) => Cow::Owned(reason.clone()),
+            None => Cow::Owned(format!(
+                "Dependency {} isn't allowed in this package.",
+                self.name
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = BannedDependencyIssue::new(
+            "moment".into(),
+            "^2.29.0".into(),
+            DependencyKind::Dependencies,
+            None,
+        );
+
+        assert_eq!(issue.name(), "banned-dependencies");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Dependency moment isn't allowed in this package."
+        );
+    }
+
+    #[test]
+    fn reason() {
+        let issue = BannedDependencyIssue::new(
+            "moment".into(),
+            "^2.29.0".into(),
+            DependencyKind::Dependencies,
+            Some("Use date-fns instead of moment.".into()),
+        );
+
+        assert_eq!(issue.why(), "Use date-fns instead of moment.");
+    }
+}
@@ -0,0 +1,229 @@
+use super::{empty_dependencies::DependencyKind, FixContext, Issue, IssueLevel, PackageType};
+use crate::packages::semversion::SemVersion;
+use anyhow::Result;
+use colored::Colorize;
+use semver::Op;
+use serde::Deserialize;
+use std::{borrow::Cow, fmt::Display, path::PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RangeStyle {
+    Exact,
+    Caret,
+    Tilde,
+}
+
+impl Display for RangeStyle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RangeStyle::Exact => write!(f, "exact versions"),
+            RangeStyle::Caret => write!(f, "caret ranges"),
+            RangeStyle::Tilde => write!(f, "tilde ranges"),
+        }
+    }
+}
+
+impl RangeStyle {
+    /// The style of a dependency version, or `None` for any other kind of range
+    /// (e.g. `>=1.0.0`, `*`, `workspace:*`) that we don't enforce a style on.
+    pub fn of(version: &str) -> Option<Self> {
+        match SemVersion::parse(version) {
+            Ok(SemVersion::Exact(_)) => Some(RangeStyle::Exact),
+            Ok(SemVersion::Range(range)) if range.comparators.len() == 1 => {
+                match range.comparators[0].op {
+                    // The semver crate parses partial versions like `1.2` as caret ranges.
+                    Op::Caret if version.trim_start().starts_with('^') => Some(RangeStyle::Caret),
+                    Op::Tilde => Some(RangeStyle::Tilde),
+                    Op::Exact => Some(RangeStyle::Exact),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Rewrite a version using this style, if it's a full version.
+    pub fn format(&self, version: &str) -> Option<String> {
+        let version = match SemVersion::parse(version).ok()? {
+            SemVersion::Exact(version) => version.to_string(),
+            SemVersion::Range(range) => {
+                let comparator = range.comparators.first()?;
+                let mut version = format!(
+                    "{}.{}.{}",
+                    comparator.major, comparator.minor?, comparator.patch?
+                );
+
+                if !comparator.pre.is_empty() {
+                    version += &format!("-{}", comparator.pre);
+                }
+
+                version
+            }
+        };
+
+        Some(match self {
+            RangeStyle::Exact => version,
+            RangeStyle::Caret => format!("^{}", version),
+            RangeStyle::Tilde => format!("~{}", version),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct DependencyRangeStyleIssue {
+    dependency_kind: DependencyKind,
+    style: RangeStyle,
+    /// The name, current version and expected version of each dependency.
+    dependencies: Vec<(String, String, Option<String>)>,
+    fixed: bool,
+}
+
+impl DependencyRangeStyleIssue {
+    pub fn new(
+        dependency_kind: DependencyKind,
+        style: RangeStyle,
+        dependencies: Vec<(String, String)>,
+    ) -> Box<Self> {
+        let dependencies = dependencies
+            .into_iter()
+            .map(|(name, version)| {
+                let expected = style.format(&version);
+                (name, version, expected)
+            })
+            .collect();
+
+        Box::new(Self {
+            dependency_kind,
+            style,
+            dependencies,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for DependencyRangeStyleIssue {
+    fn name(&self) -> &str {
+        "dependency-range-style"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Error,
+        }
+    }
+
+    fn message(&self) -> String {
+        let dependencies = self
+            .dependencies
+            .iter()
+            .map(|(name, version, expected)| {
+                let indicator = match expected {
+                    Some(expected) => format!(r#"← should be "{}"."#, expected),
+                    None => format!("← should use {}.", self.style),
+                };
+
+                format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "~".yellow(),
+                    name.white(),
+                    version.yellow(),
+                    indicator.yellow(),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+  │   "{}": {{
+{}
+  │   }}
+  │ }}"#,
+            self.dependency_kind.to_string().white(),
+            dependencies,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{} should use {}.",
+            self.dependency_kind, self.style
+        ))
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        let path = match package_type {
+            PackageType::Package(path) => PathBuf::from(path).join("package.json"),
+            PackageType::Root => context.root.join("package.json"),
+            PackageType::None => return Ok(()),
+        };
+
+        let mut value = context.edit_json(&path)?;
+
+        if let Some(dependencies) = value
+            .get_mut(self.dependency_kind.to_string())
+            .and_then(|dependencies| dependencies.as_object_mut())
+        {
+            for (name, _, expected) in &self.dependencies {
+                if let (Some(dependency), Some(expected)) = (dependencies.get_mut(name), expected) {
+                    *dependency = serde_json::Value::String(expected.clone());
+                }
+            }
+
+            self.fixed = self
+                .dependencies
+                .iter()
+                .all(|(_, _, expected)| expected.is_some());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = DependencyRangeStyleIssue::new(
+            DependencyKind::Dependencies,
+            RangeStyle::Exact,
+            vec![
+                ("react".into(), "^18.2.0".into()),
+                ("next".into(), "~14.1".into()),
+            ],
+        );
+
+        assert_eq!(issue.name(), "dependency-range-style");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(issue.why(), "dependencies should use exact versions.");
+    }
+
+    #[test]
+    fn range_style() {
+        assert_eq!(RangeStyle::of("1.2.3"), Some(RangeStyle::Exact));
+        assert_eq!(RangeStyle::of("=1.2.3"), Some(RangeStyle::Exact));
+        assert_eq!(RangeStyle::of("^1.2.3"), Some(RangeStyle::Caret));
+        assert_eq!(RangeStyle::of("~1.2"), Some(RangeStyle::Tilde));
+        assert_eq!(RangeStyle::of("1.2"), None);
+        assert_eq!(RangeStyle::of(">=1.2.3"), None);
+        assert_eq!(RangeStyle::of("*"), None);
+        assert_eq!(RangeStyle::of("workspace:*"), None);
+
+        assert_eq!(RangeStyle::Exact.format("^1.2.3"), Some("1.2.3".into()));
+        assert_eq!(
+            RangeStyle::Caret.format("1.2.3-beta.1"),
+            Some("^1.2.3-beta.1".into())
+        );
+        assert_eq!(RangeStyle::Tilde.format("^1.2.3"), Some("~1.2.3".into()));
+        assert_eq!(RangeStyle::Exact.format("^1.2"), None);
+    }
+}
@@ -1,10 +1,9 @@
-use super::{Issue, IssueLevel, PackageType};
-use crate::json::{self};
+use super::{FixContext, Issue, IssueLevel, PackageType};
 use anyhow::Result;
 use colored::Colorize;
-use std::{borrow::Cow, fmt::Display, fs, path::PathBuf};
+use std::{borrow::Cow, fmt::Display, path::PathBuf};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum DependencyKind {
     Dependencies,
     DevDependencies,
@@ -12,6 +11,15 @@ pub enum DependencyKind {
     OptionalDependencies,
 }
 
+impl DependencyKind {
+    pub const ALL: [DependencyKind; 4] = [
+        DependencyKind::Dependencies,
+        DependencyKind::DevDependencies,
+        DependencyKind::PeerDependencies,
+        DependencyKind::OptionalDependencies,
+    ];
+}
+
 impl Display for DependencyKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -68,20 +76,19 @@ impl Issue for EmptyDependenciesIssue {
         Cow::Borrowed("package.json should not have empty dependencies fields.")
     }
 
-    fn fix(&mut self, package_type: &PackageType) -> Result<()> {
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
         if let PackageType::Package(path) = package_type {
             let path = PathBuf::from(path).join("package.json");
-            let value = fs::read_to_string(&path)?;
-            let (mut value, indent, lineending) = json::deserialize::<serde_json::Value>(&value)?;
+            let mut value = context.edit_json(&path)?;
             let dependency = self.dependency_kind.to_string();
 
             if let Some(dependency_field) = value.get(&dependency) {
                 if dependency_field.is_object() && dependency_field.as_object().unwrap().is_empty()
                 {
-                    value.as_object_mut().unwrap().remove(&dependency);
-
-                    let value = json::serialize(&value, indent, lineending)?;
-                    fs::write(path, value)?;
+                    value
+                        .as_object_mut()
+                        .unwrap()
+                        .retain(|key, _| *key != dependency);
 
                     self.fixed = true;
                 }
@@ -0,0 +1,142 @@
+use super::{FixContext, Issue, IssueLevel, PackageType};
+use anyhow::Result;
+use colored::Colorize;
+use std::{borrow::Cow, path::PathBuf};
+
+#[derive(Debug)]
+pub struct IncompatibleEnginesIssue {
+    engine: String,
+    range: String,
+    expected_range: String,
+    /// The package the expected range comes from, or `None` for the root package.
+    expected_package: Option<String>,
+    fixed: bool,
+}
+
+impl IncompatibleEnginesIssue {
+    pub fn new(
+        engine: String,
+        range: String,
+        expected_range: String,
+        expected_package: Option<String>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            engine,
+            range,
+            expected_range,
+            expected_package,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for IncompatibleEnginesIssue {
+    fn name(&self) -> &str {
+        "incompatible-engines"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Error,
+        }
+    }
+
+    fn message(&self) -> String {
+        let expected_package = match &self.expected_package {
+            Some(package) => format!("{}/package.json", package),
+            None => "./package.json".to_string(),
+        };
+
+        format!(
+            r#"  │ {{
+  │   "{}": {{
+  {}     "{}": "{}"   {}
+  │   }}
+  │ }}"#,
+            "engines".white(),
+            "~".yellow(),
+            self.engine.white(),
+            self.range.yellow(),
+            format!(
+                r#"← doesn't match "{}" from {}."#,
+                self.expected_range, expected_package
+            )
+            .yellow(),
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "The {} engine range should be compatible across the monorepo.",
+            self.engine
+        ))
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        // Only align packages with the root package, since we can't know which
+        // package is right when they conflict with each other.
+        if self.expected_package.is_some() {
+            return Ok(());
+        }
+
+        if let PackageType::Package(path) = package_type {
+            let path = PathBuf::from(path).join("package.json");
+            let mut value = context.edit_json(&path)?;
+
+            if let Some(engines) = value
+                .get_mut("engines")
+                .and_then(|engines| engines.as_object_mut())
+            {
+                engines.insert(
+                    self.engine.clone(),
+                    serde_json::Value::String(self.expected_range.clone()),
+                );
+
+                self.fixed = true;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = IncompatibleEnginesIssue::new("node".into(), ">=16".into(), "^20".into(), None);
+
+        assert_eq!(issue.name(), "incompatible-engines");
+        assert_eq!(issue.level(), IssueLevel::Error);
+        assert_eq!(
+            issue.why(),
+            "The node engine range should be compatible across the monorepo."
+        );
+    }
+
+    #[test]
+    fn root() {
+        let issue = IncompatibleEnginesIssue::new("node".into(), "^16".into(), "^20".into(), None);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn package() {
+        let issue = IncompatibleEnginesIssue::new(
+            "node".into(),
+            "^16".into(),
+            "^20".into(),
+            Some("./packages/abc".into()),
+        );
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+}
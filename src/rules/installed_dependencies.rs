@@ -0,0 +1,114 @@
+use super::{empty_dependencies::DependencyKind, Issue, IssueLevel};
+use colored::Colorize;
+use indexmap::IndexMap;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct InstalledDependenciesIssue {
+    /// The kind, name, declared version and installed version (if any) of each dependency.
+    dependencies: Vec<(DependencyKind, String, String, Option<String>)>,
+}
+
+impl InstalledDependenciesIssue {
+    pub fn new(dependencies: Vec<(DependencyKind, String, String, Option<String>)>) -> Box<Self> {
+        Box::new(Self { dependencies })
+    }
+}
+
+impl Issue for InstalledDependenciesIssue {
+    fn name(&self) -> &str {
+        "installed-dependencies"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        let mut dependency_kinds = IndexMap::<String, Vec<String>>::new();
+
+        for (dependency_kind, name, version, installed_version) in &self.dependencies {
+            let indicator = match installed_version {
+                Some(installed_version) => format!("← but {} is installed.", installed_version),
+                None => "← but it isn't installed.".to_string(),
+            };
+
+            dependency_kinds
+                .entry(dependency_kind.to_string())
+                .or_default()
+                .push(format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "-".red(),
+                    name.white(),
+                    version.white(),
+                    indicator.red(),
+                ));
+        }
+
+        let dependency_kinds = dependency_kinds
+            .into_iter()
+            .map(|(dependency_kind, dependencies)| {
+                format!(
+                    r#"  │   "{}": {{
+{}
+  │   }}"#,
+                    dependency_kind.white(),
+                    dependencies.join("\n"),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+{}
+  │ }}"#,
+            dependency_kinds,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Installed dependencies should match the declared versions. Run your package manager's install command.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = InstalledDependenciesIssue::new(vec![
+            (
+                DependencyKind::Dependencies,
+                "react".into(),
+                "^18.2.0".into(),
+                Some("17.0.2".into()),
+            ),
+            (
+                DependencyKind::Dependencies,
+                "next".into(),
+                "14.1.0".into(),
+                None,
+            ),
+            (
+                DependencyKind::DevDependencies,
+                "typescript".into(),
+                "^5.0.0".into(),
+                None,
+            ),
+        ]);
+
+        assert_eq!(issue.name(), "installed-dependencies");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Installed dependencies should match the declared versions. Run your package manager's install command."
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use super::{Issue, IssueLevel};
+use crate::packages::exports::ExportsError;
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct InvalidExportsIssue {
+    errors: Vec<ExportsError>,
+}
+
+impl InvalidExportsIssue {
+    pub fn new(errors: Vec<ExportsError>) -> Box<Self> {
+        Box::new(Self { errors })
+    }
+}
+
+impl Issue for InvalidExportsIssue {
+    fn name(&self) -> &str {
+        "invalid-exports"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        let errors = self
+            .errors
+            .iter()
+            .map(|error| {
+                let mut location = error
+                    .keys
+                    .iter()
+                    .map(|key| format!(r#""{}""#, key.white()))
+                    .collect::<Vec<_>>()
+                    .join(" → ");
+
+                if let Some(target) = &error.target {
+                    if !location.is_empty() {
+                        location += ": ";
+                    }
+
+                    location += &format!(r#""{}""#, target.white());
+                }
+
+                if location.is_empty() {
+                    location = "...".to_string();
+                }
+
+                format!(
+                    "  {}     {}   {}",
+                    "-".red(),
+                    location,
+                    format!("← {}", error.problem).red(),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+  │   "{}": {{
+{}
+  │   }}
+  │ }}"#,
+            "exports".white(),
+            errors,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("The exports field should be valid and point to existing files.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::packages::exports::ExportsProblem;
+
+    #[test]
+    fn test() {
+        let issue = InvalidExportsIssue::new(vec![
+            ExportsError {
+                keys: Vec::new(),
+                target: None,
+                problem: ExportsProblem::MixedKeys,
+            },
+            ExportsError {
+                keys: vec![".".into(), "types".into()],
+                target: None,
+                problem: ExportsProblem::TypesNotFirst,
+            },
+            ExportsError {
+                keys: vec!["./utils".into(), "import".into()],
+                target: Some("dist/utils.mjs".into()),
+                problem: ExportsProblem::NotRelative,
+            },
+            ExportsError {
+                keys: vec![],
+                target: Some("./dist/index.js".into()),
+                problem: ExportsProblem::NonExistantFile,
+            },
+        ]);
+
+        assert_eq!(issue.name(), "invalid-exports");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "The exports field should be valid and point to existing files."
+        );
+    }
+}
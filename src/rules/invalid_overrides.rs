@@ -0,0 +1,133 @@
+use super::{Issue, IssueLevel};
+use crate::packages::overrides::{Override, OverridesField};
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct InvalidOverridesIssue {
+    /// Overrides and the `(package, range)` declarations they conflict with.
+    conflicts: Vec<(Override, Vec<(String, String)>)>,
+    /// Override fields declared outside of the root package.json.
+    non_root_fields: Vec<OverridesField>,
+}
+
+impl InvalidOverridesIssue {
+    pub fn new(conflicts: Vec<(Override, Vec<(String, String)>)>) -> Box<Self> {
+        Box::new(Self {
+            conflicts,
+            non_root_fields: Vec::new(),
+        })
+    }
+
+    pub fn new_non_root(non_root_fields: Vec<OverridesField>) -> Box<Self> {
+        Box::new(Self {
+            conflicts: Vec::new(),
+            non_root_fields,
+        })
+    }
+}
+
+impl Issue for InvalidOverridesIssue {
+    fn name(&self) -> &str {
+        "invalid-overrides"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        let mut fields = Vec::new();
+
+        for field in &self.non_root_fields {
+            fields.push(format!(
+                r#"  {}   "{}": {{ ... }}   {}"#,
+                "-".red(),
+                field.to_string().white(),
+                "← only used in the root package.json.".red(),
+            ));
+        }
+
+        let mut conflicts = self.conflicts.iter().peekable();
+
+        while let Some((item, _)) = conflicts.peek() {
+            let field = item.field;
+            let mut lines = Vec::new();
+
+            while let Some((item, declared)) = conflicts.next_if(|(item, _)| item.field == field) {
+                let declared = declared
+                    .iter()
+                    .map(|(package, range)| format!("{} declares {}", package, range))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                lines.push(format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "~".yellow(),
+                    item.key.white(),
+                    item.version.yellow(),
+                    format!("← but {}.", declared).yellow(),
+                ));
+            }
+
+            fields.push(format!(
+                r#"  │   "{}": {{
+{}
+  │   }}"#,
+                field.to_string().white(),
+                lines.join("\n"),
+            ));
+        }
+
+        format!(
+            r#"  │ {{
+{}
+  │ }}"#,
+            fields.join("\n"),
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Overrides should be compatible with the versions declared in the workspace, and only be declared in the root package.json.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = InvalidOverridesIssue::new(vec![(
+            Override {
+                field: OverridesField::Pnpm,
+                key: "react".into(),
+                name: "react".into(),
+                version: "17.0.2".into(),
+                range: None,
+                top_level: true,
+            },
+            vec![("packages/abc".into(), "^18.2.0".into())],
+        )]);
+
+        assert_eq!(issue.name(), "invalid-overrides");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Overrides should be compatible with the versions declared in the workspace, and only be declared in the root package.json."
+        );
+    }
+
+    #[test]
+    fn non_root() {
+        let issue = InvalidOverridesIssue::new_non_root(vec![OverridesField::Npm]);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+}
@@ -0,0 +1,152 @@
+use super::{FixContext, Issue, IssueLevel, PackageType};
+use crate::lockfile::LockfileKind;
+use anyhow::Result;
+use colored::Colorize;
+use std::{borrow::Cow, path::PathBuf};
+
+pub const KNOWN_PACKAGE_MANAGERS: [&str; 4] = ["npm", "pnpm", "yarn", "bun"];
+
+#[derive(Debug, PartialEq)]
+pub enum PackageManagerProblem {
+    /// Not `<name>@<version>[+<hash>]`.
+    InvalidFormat,
+    UnknownPackageManager(String),
+    /// The package managers allowed by `devEngines.packageManager`.
+    DevEngines(Vec<String>),
+    Lockfile(LockfileKind),
+    /// A workspace package declaring a different package manager than the root.
+    Root(String),
+}
+
+#[derive(Debug)]
+pub struct InvalidPackageManagerIssue {
+    value: String,
+    problem: PackageManagerProblem,
+    fixed: bool,
+}
+
+impl InvalidPackageManagerIssue {
+    pub fn new(value: String, problem: PackageManagerProblem) -> Box<Self> {
+        Box::new(Self {
+            value,
+            problem,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for InvalidPackageManagerIssue {
+    fn name(&self) -> &str {
+        "invalid-package-manager"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Error,
+        }
+    }
+
+    fn message(&self) -> String {
+        let indicator = match &self.problem {
+            PackageManagerProblem::InvalidFormat => {
+                "← should be <name>@<version>, e.g. pnpm@9.0.0.".to_string()
+            }
+            PackageManagerProblem::UnknownPackageManager(name) => format!(
+                "← unknown package manager {}, should be {}.",
+                name,
+                KNOWN_PACKAGE_MANAGERS.join(", ")
+            ),
+            PackageManagerProblem::DevEngines(expected) => format!(
+                "← but devEngines.packageManager requires {}.",
+                expected.join(" or ")
+            ),
+            PackageManagerProblem::Lockfile(lockfile_kind) => {
+                format!("← but the lockfile is {}.", lockfile_kind)
+            }
+            PackageManagerProblem::Root(root) => {
+                format!("← but the root package.json uses {}.", root)
+            }
+        };
+
+        format!(
+            r#"  │ {{
+  │   ...
+  {}   "{}": "{}"   {}
+  │   ...
+  │ }}"#,
+            "~".yellow(),
+            "packageManager".white(),
+            self.value.yellow(),
+            indicator.yellow(),
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("The `packageManager` field should use a known package manager with an exact version, consistent with `devEngines.packageManager`, the lockfile and the root package.json.")
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        // Only conflicts with the root package.json have an obvious fix.
+        let (PackageType::Package(path), PackageManagerProblem::Root(root)) =
+            (package_type, &self.problem)
+        else {
+            return Ok(());
+        };
+
+        let path = PathBuf::from(path).join("package.json");
+        let mut value = context.edit_json(&path)?;
+
+        if let Some(package_manager) = value.get_mut("packageManager") {
+            *package_manager = serde_json::Value::String(root.clone());
+        }
+
+        self.fixed = true;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue =
+            InvalidPackageManagerIssue::new("pnpm@^9".into(), PackageManagerProblem::InvalidFormat);
+
+        assert_eq!(issue.name(), "invalid-package-manager");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "The `packageManager` field should use a known package manager with an exact version, consistent with `devEngines.packageManager`, the lockfile and the root package.json."
+        );
+    }
+
+    #[test]
+    fn test_lockfile() {
+        let issue = InvalidPackageManagerIssue::new(
+            "pnpm@9.0.0".into(),
+            PackageManagerProblem::Lockfile(LockfileKind::Yarn),
+        );
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn test_root() {
+        let issue = InvalidPackageManagerIssue::new(
+            "yarn@4.0.0".into(),
+            PackageManagerProblem::Root("pnpm@9.0.0".into()),
+        );
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+}
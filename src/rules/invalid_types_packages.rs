@@ -0,0 +1,194 @@
+use super::{empty_dependencies::DependencyKind, FixContext, Issue, IssueLevel, PackageType};
+use anyhow::Result;
+use colored::Colorize;
+use std::{borrow::Cow, path::PathBuf};
+
+#[derive(Debug, PartialEq)]
+pub enum TypesProblem {
+    /// The library ships its own types.
+    BundledTypes,
+    /// The major version differs from the installed library's.
+    MajorMismatch { library_version: String },
+}
+
+#[derive(Debug)]
+pub struct TypesPackage {
+    pub name: String,
+    pub version: String,
+    pub dependency_kind: DependencyKind,
+    pub problem: TypesProblem,
+}
+
+impl TypesPackage {
+    /// The library these types are for, e.g. `@types/babel__core` => `@babel/core`.
+    pub fn library(name: &str) -> Option<String> {
+        let name = name.strip_prefix("@types/")?;
+
+        Some(match name.split_once("__") {
+            Some((scope, name)) => format!("@{}/{}", scope, name),
+            None => name.to_string(),
+        })
+    }
+
+    fn expected_version(&self) -> Option<String> {
+        match &self.problem {
+            TypesProblem::BundledTypes => None,
+            TypesProblem::MajorMismatch { library_version } => {
+                let major = library_version.split('.').next()?;
+                Some(format!("^{}.0.0", major))
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InvalidTypesPackagesIssue {
+    packages: Vec<TypesPackage>,
+    fixed: bool,
+}
+
+impl InvalidTypesPackagesIssue {
+    pub fn new(packages: Vec<TypesPackage>) -> Box<Self> {
+        Box::new(Self {
+            packages,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for InvalidTypesPackagesIssue {
+    fn name(&self) -> &str {
+        "invalid-types-packages"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Error,
+        }
+    }
+
+    fn message(&self) -> String {
+        let library =
+            |package: &TypesPackage| TypesPackage::library(&package.name).unwrap_or_default();
+
+        let packages = self
+            .packages
+            .iter()
+            .map(|package| match &package.problem {
+                TypesProblem::BundledTypes => format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "-".red(),
+                    package.name.white(),
+                    package.version.white(),
+                    format!("← {} already ships its own types.", library(package)).red(),
+                ),
+                TypesProblem::MajorMismatch { library_version } => format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "~".yellow(),
+                    package.name.white(),
+                    package.version.yellow(),
+                    format!(
+                        "← but {} {} is installed.",
+                        library(package),
+                        library_version
+                    )
+                    .yellow(),
+                ),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+  │   ...
+{}
+  │   ...
+  │ }}"#,
+            packages,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("@types packages should only be used for libraries without types, with the same major version.")
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        let path = match package_type {
+            PackageType::Package(path) => PathBuf::from(path).join("package.json"),
+            PackageType::Root => context.root.join("package.json"),
+            PackageType::None => return Ok(()),
+        };
+
+        let mut value = context.edit_json(&path)?;
+
+        for package in &self.packages {
+            if let Some(dependencies) = value
+                .get_mut(package.dependency_kind.to_string())
+                .and_then(|dependencies| dependencies.as_object_mut())
+            {
+                match package.expected_version() {
+                    Some(version) => {
+                        if let Some(dependency) = dependencies.get_mut(&package.name) {
+                            *dependency = serde_json::Value::String(version);
+                        }
+                    }
+                    None => {
+                        // Keep the order of the remaining dependencies.
+                        dependencies.retain(|name, _| name != &package.name);
+                    }
+                }
+            }
+        }
+
+        self.fixed = true;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = InvalidTypesPackagesIssue::new(vec![
+            TypesPackage {
+                name: "@types/zod".into(),
+                version: "^3.0.0".into(),
+                dependency_kind: DependencyKind::DevDependencies,
+                problem: TypesProblem::BundledTypes,
+            },
+            TypesPackage {
+                name: "@types/react".into(),
+                version: "^17.0.0".into(),
+                dependency_kind: DependencyKind::DevDependencies,
+                problem: TypesProblem::MajorMismatch {
+                    library_version: "18.2.0".into(),
+                },
+            },
+        ]);
+
+        assert_eq!(issue.name(), "invalid-types-packages");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "@types packages should only be used for libraries without types, with the same major version."
+        );
+    }
+
+    #[test]
+    fn library() {
+        assert_eq!(TypesPackage::library("@types/react"), Some("react".into()));
+        assert_eq!(
+            TypesPackage::library("@types/babel__core"),
+            Some("@babel/core".into())
+        );
+        assert_eq!(TypesPackage::library("react"), None);
+    }
+}
@@ -0,0 +1,171 @@
+use super::{empty_dependencies::DependencyKind, FixContext, Issue, IssueLevel, PackageType};
+use anyhow::Result;
+use colored::Colorize;
+use std::{borrow::Cow, path::PathBuf};
+
+#[derive(Debug)]
+pub struct MissingDependency {
+    pub name: String,
+    /// The first file importing the dependency, relative to the package.
+    pub file: String,
+    pub dependency_kind: DependencyKind,
+    /// The version used by other packages of the workspace, if any.
+    pub version: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct MissingDependenciesIssue {
+    dependencies: Vec<MissingDependency>,
+    fixed: bool,
+}
+
+impl MissingDependenciesIssue {
+    pub fn new(dependencies: Vec<MissingDependency>) -> Box<Self> {
+        Box::new(Self {
+            dependencies,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for MissingDependenciesIssue {
+    fn name(&self) -> &str {
+        "missing-dependencies"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Error,
+        }
+    }
+
+    fn message(&self) -> String {
+        [
+            DependencyKind::Dependencies,
+            DependencyKind::DevDependencies,
+        ]
+        .iter()
+        .filter_map(|dependency_kind| {
+            let dependencies = self
+                .dependencies
+                .iter()
+                .filter(|dependency| dependency.dependency_kind == *dependency_kind)
+                .map(|dependency| {
+                    let version = match &dependency.version {
+                        Some(version) => format!(r#": "{}""#, version.green()),
+                        None => String::new(),
+                    };
+
+                    format!(
+                        r#"  {}     "{}"{}   {}"#,
+                        "+".green(),
+                        dependency.name.white(),
+                        version,
+                        format!("← imported in {}.", dependency.file).green(),
+                    )
+                })
+                .collect::<Vec<String>>();
+
+            match dependencies.is_empty() {
+                true => None,
+                false => Some(format!(
+                    r#"  │ {{
+  │   "{}": {{
+{}
+  │   }}
+  │ }}"#,
+                    dependency_kind.to_string().white(),
+                    dependencies.join("\n"),
+                )),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Imported dependencies should be declared in package.json, instead of relying on hoisting.")
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        if let PackageType::Package(path) = package_type {
+            let path = PathBuf::from(path).join("package.json");
+            let mut value = context.edit_json(&path)?;
+
+            let Some(object) = value.as_object_mut() else {
+                return Ok(());
+            };
+
+            for dependency in &self.dependencies {
+                let Some(version) = &dependency.version else {
+                    continue;
+                };
+
+                let dependencies = object
+                    .entry(dependency.dependency_kind.to_string())
+                    .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+
+                if let Some(dependencies) = dependencies.as_object_mut() {
+                    // Insert the dependency in alphabetical order.
+                    let mut entries = std::mem::take(dependencies).into_iter().collect::<Vec<_>>();
+                    let index = entries
+                        .iter()
+                        .position(|(name, _)| name > &dependency.name)
+                        .unwrap_or(entries.len());
+
+                    entries.insert(
+                        index,
+                        (
+                            dependency.name.clone(),
+                            serde_json::Value::String(version.clone()),
+                        ),
+                    );
+                    dependencies.extend(entries);
+                }
+            }
+
+            self.fixed = self
+                .dependencies
+                .iter()
+                .all(|dependency| dependency.version.is_some());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = MissingDependenciesIssue::new(vec![
+            MissingDependency {
+                name: "zod".into(),
+                file: "src/index.ts".into(),
+                dependency_kind: DependencyKind::Dependencies,
+                version: Some("^3.22.0".into()),
+            },
+            MissingDependency {
+                name: "vitest".into(),
+                file: "src/index.test.ts".into(),
+                dependency_kind: DependencyKind::DevDependencies,
+                version: None,
+            },
+        ]);
+
+        assert_eq!(issue.name(), "missing-dependencies");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Imported dependencies should be declared in package.json, instead of relying on hoisting."
+        );
+    }
+}
@@ -1,21 +1,44 @@
+use crate::fix::EditPlan;
 use anyhow::{anyhow, Result};
 use colored::Colorize;
 use indexmap::IndexMap;
 use std::{
     borrow::Cow,
+    cell::RefMut,
     fmt::{Debug, Display},
+    path::Path,
 };
 
+pub mod banned_dependencies;
+pub mod dependency_range_style;
 pub mod empty_dependencies;
+pub mod incompatible_engines;
+pub mod installed_dependencies;
+pub mod invalid_exports;
+pub mod invalid_overrides;
+pub mod invalid_package_manager;
+pub mod invalid_types_packages;
+pub mod missing_dependencies;
 pub mod multiple_dependency_versions;
+pub mod multiple_resolved_versions;
+pub mod non_existant_entrypoints;
 pub mod non_existant_packages;
+pub mod orphan_packages;
+pub mod outdated_lockfile;
+pub mod package_files_field;
+pub mod package_license_field;
+pub mod package_repository_field;
+pub mod package_version_field;
 pub mod packages_without_package_json;
 pub mod root_package_dependencies;
 pub mod root_package_manager_field;
 pub mod root_package_private_field;
 pub mod types_in_dependencies;
 pub mod unordered_dependencies;
+pub mod unordered_package_keys;
 pub mod unsync_similar_dependencies;
+pub mod unused_dependencies;
+pub mod unused_overrides;
 
 pub const ERROR: &str = "⨯";
 pub const WARNING: &str = "⚠️";
@@ -56,13 +79,46 @@ pub trait Issue {
     fn message(&self) -> String;
     fn why(&self) -> Cow<'static, str>;
 
-    fn fix(&mut self, _package_type: &PackageType) -> Result<()> {
+    /// Whether an issue that isn't reported on a single package (e.g. a
+    /// dependency with multiple versions) involves the given package.
+    fn involves_package(&self, _path: &str) -> bool {
+        true
+    }
+
+    fn fix(&mut self, _package_type: &PackageType, _context: &FixContext) -> Result<()> {
         Ok(())
     }
 }
 
 pub type BoxIssue = Box<dyn Issue>;
 
+/// Where fixes are applied, since the current directory can be anywhere.
+pub struct FixContext<'a> {
+    /// The monorepo root, which root files (e.g. `package.json`) are relative to.
+    pub root: &'a Path,
+    /// Whether fixes can prompt, e.g. to select a version.
+    pub interactive: bool,
+    files: &'a EditPlan,
+}
+
+impl FixContext<'_> {
+    /// Edit a JSON file, including the edits of previous fixes. The file is
+    /// parsed once and serialized once all fixes succeed.
+    pub fn edit_json(&self, path: &Path) -> Result<RefMut<'_, serde_json::Value>> {
+        self.files.edit_json(path)
+    }
+
+    /// Read a file, including the changes of previous fixes.
+    pub fn read(&self, path: &Path) -> Result<String> {
+        self.files.read(path)
+    }
+
+    /// Stage a file, which is only written once all fixes succeed.
+    pub fn write(&self, path: &Path, contents: String) {
+        self.files.write(path, contents)
+    }
+}
+
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
 pub enum PackageType {
     None,
@@ -119,10 +175,30 @@ impl<'a> IssuesList<'a> {
             .count()
     }
 
-    pub fn fix(&mut self) -> Result<()> {
+    /// Only keep the issues of the given package, including the issues across
+    /// packages that involve it.
+    pub fn retain_package(&mut self, path: &str) {
+        self.issues
+            .retain(|package_type, issues| match package_type {
+                PackageType::None => {
+                    issues.retain(|issue| issue.involves_package(path));
+                    !issues.is_empty()
+                }
+                PackageType::Package(current) => current == path,
+                PackageType::Root => false,
+            });
+    }
+
+    pub fn fix(&mut self, root: &Path, files: &EditPlan, interactive: bool) -> Result<()> {
+        let context = FixContext {
+            root,
+            interactive,
+            files,
+        };
+
         for (package_type, issues) in self.issues.iter_mut() {
             for issue in issues {
-                if let Err(error) = issue.fix(package_type) {
+                if let Err(error) = issue.fix(package_type, &context) {
                     return Err(anyhow!("Error while fixing {}: {}", package_type, error));
                 }
             }
@@ -144,7 +220,10 @@ impl IntoIterator for IssuesList<'_> {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::packages::semversion::SemVersion;
     use crate::rules::{
+        multiple_dependency_versions::MultipleDependencyVersionsIssue,
+        orphan_packages::OrphanPackagesIssue,
         root_package_dependencies::RootPackageDependenciesIssue,
         root_package_manager_field::RootPackageManagerFieldIssue,
     };
@@ -176,6 +255,51 @@ mod test {
         assert_eq!(issues.total_len(), 1);
     }
 
+    #[test]
+    fn retain_package() {
+        let ignored_issues = Vec::new();
+        let mut issues = IssuesList::new(&ignored_issues);
+
+        issues.add_raw(PackageType::Root, RootPackageManagerFieldIssue::new());
+        issues.add_raw(
+            PackageType::Package("./packages/abc".into()),
+            RootPackageDependenciesIssue::new(),
+        );
+
+        issues.add_raw(
+            PackageType::None,
+            MultipleDependencyVersionsIssue::new(
+                "react".into(),
+                indexmap::indexmap! {
+                    "./packages/abc".into() => SemVersion::parse("18.2.0").unwrap(),
+                    "./packages/def".into() => SemVersion::parse("18.3.1").unwrap(),
+                },
+                None,
+            ),
+        );
+        issues.add_raw(
+            PackageType::None,
+            MultipleDependencyVersionsIssue::new(
+                "next".into(),
+                indexmap::indexmap! {
+                    "./packages/def".into() => SemVersion::parse("14.0.0").unwrap(),
+                    "./packages/ghi".into() => SemVersion::parse("14.1.0").unwrap(),
+                },
+                None,
+            ),
+        );
+
+        issues.add_raw(
+            PackageType::None,
+            OrphanPackagesIssue::new(false, vec!["packages/*".into()], vec!["tools/xyz".into()]),
+        );
+
+        issues.retain_package("./packages/abc");
+        assert_eq!(issues.total_len(), 2);
+        assert_eq!(issues.len_by_level(IssueLevel::Warning), 1);
+        assert_eq!(issues.len_by_level(IssueLevel::Error), 1);
+    }
+
     #[test]
     fn len_by_level() {
         let ignored_issues = Vec::new();
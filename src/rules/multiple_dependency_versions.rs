@@ -1,17 +1,18 @@
-use super::{Issue, IssueLevel, PackageType};
-use crate::{
-    args::AutofixSelect, json, packages::semversion::SemVersion, printer::get_render_config,
-};
+use super::{FixContext, Issue, IssueLevel, PackageType};
+use crate::{args::AutofixSelect, packages::semversion::SemVersion, printer::get_render_config};
 use anyhow::Result;
 use colored::Colorize;
 use indexmap::IndexMap;
 use inquire::Select;
-use std::{borrow::Cow, fs, path::PathBuf};
+use std::{borrow::Cow, path::PathBuf};
 
 #[derive(Debug)]
 pub struct MultipleDependencyVersionsIssue {
     name: String,
     versions: IndexMap<String, SemVersion>,
+    /// Peer dependency ranges incompatible with the versions above, which are
+    /// reported but never autofixed.
+    peers: IndexMap<String, String>,
     select: Option<AutofixSelect>,
     fixed: bool,
 }
@@ -27,12 +28,26 @@ impl MultipleDependencyVersionsIssue {
         Box::new(Self {
             name,
             versions,
+            peers: IndexMap::new(),
             select,
             fixed: false,
         })
     }
 
-    fn get_autofix_version(&self) -> Result<Option<String>> {
+    pub fn with_peers(mut self: Box<Self>, peers: IndexMap<String, String>) -> Box<Self> {
+        self.peers = peers;
+        self
+    }
+
+    /// Whether the dependency itself has multiple versions, rather than only
+    /// incompatible peer dependency ranges.
+    fn has_multiple_versions(&self) -> bool {
+        self.versions
+            .values()
+            .any(|version| Some(version) != self.versions.values().next())
+    }
+
+    fn get_autofix_version(&self, interactive: bool) -> Result<Option<String>> {
         let mut sorted_versions = self.versions.values().collect::<Vec<_>>();
         sorted_versions.sort_by(|a, b| b.cmp(a));
 
@@ -42,6 +57,9 @@ impl MultipleDependencyVersionsIssue {
                 AutofixSelect::Lowest => sorted_versions.last().map(|v| v.to_string()),
             };
             Ok(autofix_version)
+        } else if !interactive {
+            // Without `--select`, the version can only be chosen by prompting.
+            Ok(None)
         } else {
             let message = format!("Select the version of {} to use:", self.name.bold());
             let mut versions = sorted_versions
@@ -122,9 +140,18 @@ impl Issue for MultipleDependencyVersionsIssue {
     fn message(&self) -> String {
         let mut group = vec![];
 
+        let peers = self.peers.iter().map(|(package, range)| {
+            (
+                package,
+                format!("{}   {}", range.red(), "✕ incompatible peer".red()),
+            )
+        });
+
         self.versions
             .iter()
-            .map(|(package, version)| {
+            .map(|(package, version)| (package, format_version(version, &self.versions, false)))
+            .chain(peers)
+            .map(|(package, formatted_version)| {
                 let mut common_path = package.split('/').collect::<Vec<_>>();
                 let mut end = common_path.pop().unwrap();
 
@@ -132,7 +159,6 @@ impl Issue for MultipleDependencyVersionsIssue {
                     end = "./";
                 }
 
-                let formatted_version = format_version(version, &self.versions, false);
                 let version_pad = " ".repeat(if end.len() >= 26 { 3 } else { 26 - end.len() });
 
                 if group.is_empty() || group != common_path {
@@ -169,19 +195,32 @@ impl Issue for MultipleDependencyVersionsIssue {
     }
 
     fn why(&self) -> Cow<'static, str> {
-        Cow::Owned(format!(
-            "Dependency {} has multiple versions defined in the workspace.",
-            self.name
-        ))
+        match self.has_multiple_versions() {
+            true => Cow::Owned(format!(
+                "Dependency {} has multiple versions defined in the workspace.",
+                self.name
+            )),
+            false => Cow::Owned(format!(
+                "Dependency {} has peer dependency ranges incompatible with the version defined in the workspace.",
+                self.name
+            )),
+        }
     }
 
-    fn fix(&mut self, _package_type: &PackageType) -> Result<()> {
-        if let Some(autofix_version) = self.get_autofix_version()? {
+    fn involves_package(&self, path: &str) -> bool {
+        self.versions.contains_key(path) || self.peers.contains_key(path)
+    }
+
+    fn fix(&mut self, _package_type: &PackageType, context: &FixContext) -> Result<()> {
+        // Only incompatible peers are reported, which need a manual fix.
+        if !self.has_multiple_versions() {
+            return Ok(());
+        }
+
+        if let Some(autofix_version) = self.get_autofix_version(context.interactive)? {
             for package in self.versions.keys() {
                 let path = PathBuf::from(package).join("package.json");
-                let value = fs::read_to_string(&path)?;
-                let (mut value, indent, lineending) =
-                    json::deserialize::<serde_json::Value>(&value)?;
+                let mut value = context.edit_json(&path)?;
 
                 if let Some(dependencies) = value.get_mut("dependencies") {
                     let dependencies = dependencies.as_object_mut().unwrap();
@@ -199,11 +238,16 @@ impl Issue for MultipleDependencyVersionsIssue {
                     }
                 }
 
-                let value = json::serialize(&value, indent, lineending)?;
-                fs::write(path, value)?;
+                if let Some(optional_dependencies) = value.get_mut("optionalDependencies") {
+                    let optional_dependencies = optional_dependencies.as_object_mut().unwrap();
+
+                    if let Some(optional_dependency) = optional_dependencies.get_mut(&self.name) {
+                        *optional_dependency = serde_json::Value::String(autofix_version.clone());
+                    }
+                }
             }
 
-            self.fixed = true;
+            self.fixed = self.peers.is_empty();
         }
 
         Ok(())
@@ -213,6 +257,8 @@ impl Issue for MultipleDependencyVersionsIssue {
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::fix::EditPlan;
+    use std::path::Path;
 
     #[test]
     fn test() {
@@ -328,4 +374,82 @@ mod test {
         colored::control::set_override(false);
         insta::assert_snapshot!(issue.message());
     }
+
+    #[test]
+    fn peers() {
+        let issue = MultipleDependencyVersionsIssue::new(
+            "react".to_string(),
+            indexmap::indexmap! {
+                "./packages/package-a".into() => SemVersion::parse("18.2.0").unwrap(),
+                "./packages/package-b".into() => SemVersion::parse("18.2.0").unwrap(),
+            },
+            None,
+        )
+        .with_peers(indexmap::indexmap! {
+            "./packages/package-c".into() => "^17.0.0".into(),
+        });
+
+        assert_eq!(
+            issue.why(),
+            "Dependency react has peer dependency ranges incompatible with the version defined in the workspace."
+        );
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn fix_optional_dependencies() {
+        let mut issue = MultipleDependencyVersionsIssue::new(
+            "fsevents".to_string(),
+            indexmap::indexmap! {
+                "packages/package-a".into() => SemVersion::parse("2.3.2").unwrap(),
+                "packages/package-b".into() => SemVersion::parse("2.3.3").unwrap(),
+            },
+            Some(AutofixSelect::Highest),
+        );
+        let files = EditPlan::default();
+        let context = FixContext {
+            root: Path::new("."),
+            interactive: false,
+            files: &files,
+        };
+        files.write(
+            Path::new("packages/package-a/package.json"),
+            r#"{"optionalDependencies":{"fsevents":"2.3.2"}}"#.into(),
+        );
+        files.write(
+            Path::new("packages/package-b/package.json"),
+            r#"{"dependencies":{"fsevents":"2.3.3"}}"#.into(),
+        );
+
+        issue.fix(&PackageType::None, &context).unwrap();
+        assert_eq!(issue.level(), IssueLevel::Fixed);
+
+        let value = files
+            .edit_json(Path::new("packages/package-a/package.json"))
+            .unwrap();
+        assert_eq!(value["optionalDependencies"]["fsevents"], "2.3.3");
+    }
+
+    #[test]
+    fn non_interactive() {
+        let mut issue = MultipleDependencyVersionsIssue::new(
+            "test".to_string(),
+            indexmap::indexmap! {
+                "./packages/package-a".into() => SemVersion::parse("1.2.3").unwrap(),
+                "./packages/package-b".into() => SemVersion::parse("1.2.4").unwrap(),
+            },
+            None,
+        );
+        let files = EditPlan::default();
+        let context = FixContext {
+            root: Path::new("."),
+            interactive: false,
+            files: &files,
+        };
+
+        issue.fix(&PackageType::None, &context).unwrap();
+        assert_eq!(issue.level(), IssueLevel::Error);
+    }
 }
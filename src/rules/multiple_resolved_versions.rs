@@ -0,0 +1,118 @@
+use super::{Issue, IssueLevel};
+use crate::lockfile::LockfileKind;
+use colored::Colorize;
+use indexmap::IndexMap;
+use semver::Version;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct MultipleResolvedVersionsIssue {
+    name: String,
+    lockfile: LockfileKind,
+    /// Each resolved version, with the packages pulling it.
+    versions: IndexMap<String, Vec<String>>,
+}
+
+impl MultipleResolvedVersionsIssue {
+    pub fn new(
+        name: String,
+        lockfile: LockfileKind,
+        mut versions: IndexMap<String, Vec<String>>,
+    ) -> Box<Self> {
+        versions.values_mut().for_each(|packages| packages.sort());
+        versions.sort_by(|a, _, b, _| match (Version::parse(a), Version::parse(b)) {
+            (Ok(a), Ok(b)) => b.cmp(&a),
+            _ => b.cmp(a),
+        });
+
+        Box::new(Self {
+            name,
+            lockfile,
+            versions,
+        })
+    }
+}
+
+impl Issue for MultipleResolvedVersionsIssue {
+    fn name(&self) -> &str {
+        "multiple-resolved-versions"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Warning
+    }
+
+    fn message(&self) -> String {
+        let version_width = self
+            .versions
+            .keys()
+            .map(|version| version.len())
+            .max()
+            .unwrap_or_default();
+
+        self.versions
+            .iter()
+            .map(|(version, packages)| {
+                let packages = packages
+                    .iter()
+                    .map(|package| match package.as_str() {
+                        "." => "./",
+                        package => package,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                format!(
+                    "  {}{}   {}",
+                    version.yellow(),
+                    " ".repeat(version_width - version.len()),
+                    format!("← {}", packages).bright_black(),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "Dependency {} is resolved to multiple versions in {}.",
+            self.name, self.lockfile
+        ))
+    }
+
+    fn involves_package(&self, path: &str) -> bool {
+        self.versions
+            .values()
+            .any(|packages| packages.iter().any(|package| package == path))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = MultipleResolvedVersionsIssue::new(
+            "react".into(),
+            LockfileKind::Pnpm,
+            indexmap::indexmap! {
+                "17.0.2".into() => vec!["packages/def".into()],
+                "18.2.0".into() => vec![".".into(), "packages/abc".into()],
+                "18.3.0-canary.1".into() => vec!["packages/ghi".into()],
+            },
+        );
+
+        assert_eq!(issue.name(), "multiple-resolved-versions");
+        assert_eq!(issue.level(), IssueLevel::Warning);
+        assert!(issue.involves_package("packages/def"));
+        assert!(!issue.involves_package("packages/jkl"));
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Dependency react is resolved to multiple versions in pnpm-lock.yaml."
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use super::{Issue, IssueLevel};
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct NonExistantEntrypointsIssue {
+    entrypoints: Vec<(String, serde_json::Value)>,
+}
+
+impl NonExistantEntrypointsIssue {
+    pub fn new(entrypoints: Vec<(String, serde_json::Value)>) -> Box<Self> {
+        Box::new(Self { entrypoints })
+    }
+}
+
+impl Issue for NonExistantEntrypointsIssue {
+    fn name(&self) -> &str {
+        "non-existant-entrypoints"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        let entrypoints = self
+            .entrypoints
+            .iter()
+            .map(|(field, target)| match target {
+                serde_json::Value::String(target) => format!(
+                    r#"  {}   "{}": "{}"   {}"#,
+                    "-".red(),
+                    field.white(),
+                    target.white(),
+                    "← but this file doesn't exist".red(),
+                ),
+                _ => format!(
+                    r#"  {}   "{}": {}   {}"#,
+                    "-".red(),
+                    field.white(),
+                    target.to_string().white(),
+                    "← should be a path to a file".red(),
+                ),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+{}
+  │ }}"#,
+            entrypoints,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("All entrypoints of a non-private package should point to existing files.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = NonExistantEntrypointsIssue::new(vec![
+            ("main".into(), "./dist/index.js".into()),
+            ("exports".into(), "./dist/index.mjs".into()),
+            ("module".into(), serde_json::json!(["./dist/index.mjs"])),
+        ]);
+
+        assert_eq!(issue.name(), "non-existant-entrypoints");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "All entrypoints of a non-private package should point to existing files."
+        );
+    }
+}
@@ -1,8 +1,7 @@
-use super::{Issue, IssueLevel, PackageType};
-use crate::json;
+use super::{FixContext, Issue, IssueLevel, PackageType};
 use anyhow::Result;
 use colored::Colorize;
-use std::{borrow::Cow, fs, path::PathBuf};
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub struct NonExistantPackagesIssue {
@@ -101,12 +100,17 @@ impl Issue for NonExistantPackagesIssue {
         Cow::Borrowed("All paths defined in the workspace should match at least one package.")
     }
 
-    fn fix(&mut self, package_type: &PackageType) -> Result<()> {
+    /// The paths don't match any package, so they can't be scoped to.
+    fn involves_package(&self, _path: &str) -> bool {
+        false
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
         if let PackageType::None = package_type {
             match self.pnpm_workspace {
                 true => {
-                    let path = PathBuf::from("pnpm-workspace.yaml");
-                    let value = fs::read_to_string(&path)?;
+                    let path = context.root.join("pnpm-workspace.yaml");
+                    let value = context.read(&path)?;
                     let mut value = serde_yaml::from_str::<serde_yaml::Value>(&value)?;
 
                     value
@@ -121,15 +125,13 @@ impl Issue for NonExistantPackagesIssue {
                         });
 
                     let value = serde_yaml::to_string(&value)?;
-                    fs::write(path, value)?;
+                    context.write(&path, value);
 
                     self.fixed = true;
                 }
                 false => {
-                    let path = PathBuf::from("package.json");
-                    let value = fs::read_to_string(&path)?;
-                    let (mut value, indent, lineending) =
-                        json::deserialize::<serde_json::Value>(&value)?;
+                    let path = context.root.join("package.json");
+                    let mut value = context.edit_json(&path)?;
 
                     value
                         .get_mut("workspaces")
@@ -142,9 +144,6 @@ impl Issue for NonExistantPackagesIssue {
                             !self.paths.contains(&package)
                         });
 
-                    let value = json::serialize(&value, indent, lineending)?;
-                    fs::write(path, value)?;
-
                     self.fixed = true;
                 }
             }
@@ -0,0 +1,256 @@
+use super::{FixContext, Issue, IssueLevel, PackageType};
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct OrphanPackagesIssue {
+    pnpm_workspace: bool,
+    packages_list: Vec<String>,
+    paths: Vec<String>,
+    fixed: bool,
+}
+
+impl OrphanPackagesIssue {
+    pub fn new(pnpm_workspace: bool, packages_list: Vec<String>, paths: Vec<String>) -> Box<Self> {
+        Box::new(Self {
+            pnpm_workspace,
+            packages_list,
+            paths,
+            fixed: false,
+        })
+    }
+
+    fn pnpm_message(&self) -> String {
+        let workspaces = self
+            .packages_list
+            .iter()
+            .map(|package| format!("  │  - '{}'", package))
+            .chain(self.paths.iter().map(|path| {
+                format!(
+                    "  {}  - '{}'   {}",
+                    "+".green(),
+                    path.white(),
+                    "← has a package.json but isn't in the workspace".green(),
+                )
+            }))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ packages:   {}
+{}"#,
+            "← Workspace has paths defined...".blue(),
+            workspaces,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn package_message(&self) -> String {
+        let workspaces = self
+            .packages_list
+            .iter()
+            .map(|package| format!(r#"  │     "{}","#, package))
+            .chain(self.paths.iter().map(|path| {
+                format!(
+                    r#"  {}     "{}",   {}"#,
+                    "+".green(),
+                    path.white(),
+                    "← has a package.json but isn't in the workspace".green(),
+                )
+            }))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+  │   "workspaces": [   {}
+{}
+  │   ],
+  │ }}"#,
+            "← Workspace has paths defined...".blue(),
+            workspaces,
+        )
+        .bright_black()
+        .to_string()
+    }
+}
+
+impl Issue for OrphanPackagesIssue {
+    fn name(&self) -> &str {
+        "orphan-packages"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Warning,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self.pnpm_workspace {
+            true => self.pnpm_message(),
+            false => self.package_message(),
+        }
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("All folders with a package.json should be matched by the workspace.")
+    }
+
+    /// Orphan packages aren't part of the workspace, so they can't be scoped to.
+    fn involves_package(&self, _path: &str) -> bool {
+        false
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        if let PackageType::None = package_type {
+            let paths = self.paths.iter().cloned();
+
+            match self.pnpm_workspace {
+                true => {
+                    let path = context.root.join("pnpm-workspace.yaml");
+                    let value = context.read(&path)?;
+
+                    // Round-tripping the YAML would lose comments and the formatting of
+                    // other keys (e.g. catalogs), so only fix block lists in place.
+                    if let Some(value) = append_pnpm_packages(&value, &self.paths) {
+                        context.write(&path, value);
+                        self.fixed = true;
+                    }
+                }
+                false => {
+                    let path = context.root.join("package.json");
+                    let mut value = context.edit_json(&path)?;
+
+                    let workspaces = value
+                        .get_mut("workspaces")
+                        .ok_or(anyhow!("No `workspaces` field in package.json"))?;
+
+                    // Yarn also accepts `{ "packages": [...], "nohoist": [...] }`.
+                    let workspaces = match workspaces.is_array() {
+                        true => workspaces.as_array_mut(),
+                        false => workspaces
+                            .get_mut("packages")
+                            .and_then(|packages| packages.as_array_mut()),
+                    }
+                    .ok_or(anyhow!("`workspaces` isn't a list in package.json"))?;
+
+                    workspaces.extend(paths.map(serde_json::Value::String));
+
+                    self.fixed = true;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Append paths to the `packages` block list of a `pnpm-workspace.yaml`, after
+/// its last entry and in the same style. Returns `None` for other list styles,
+/// e.g. `packages: [...]`.
+fn append_pnpm_packages(source: &str, paths: &[String]) -> Option<String> {
+    let lines = source.split_inclusive('\n').collect::<Vec<_>>();
+    let start = lines.iter().position(|line| {
+        line.strip_prefix("packages:")
+            .map(str::trim)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('#'))
+    })?;
+
+    let mut last_entry = None;
+
+    for (index, line) in lines.iter().enumerate().skip(start + 1) {
+        let trimmed = line.trim();
+
+        if trimmed.starts_with('-') {
+            last_entry = Some(index);
+        } else if !trimmed.is_empty() && !trimmed.starts_with('#') {
+            break;
+        }
+    }
+
+    let last_entry = last_entry?;
+    let line = lines[last_entry];
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let quote = match line
+        .trim_start()
+        .trim_start_matches('-')
+        .trim_start()
+        .chars()
+        .next()
+    {
+        Some(quote @ ('"' | '\'')) => quote.to_string(),
+        _ => String::new(),
+    };
+    let line_ending = match line.ends_with("\r\n") {
+        true => "\r\n",
+        false => "\n",
+    };
+
+    let mut value = lines[..=last_entry].concat();
+
+    if !value.ends_with('\n') {
+        value.push_str(line_ending);
+    }
+
+    for path in paths {
+        value.push_str(&format!(
+            "{}- {}{}{}{}",
+            indent, quote, path, quote, line_ending
+        ));
+    }
+
+    value.push_str(&lines[last_entry + 1..].concat());
+    Some(value)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue =
+            OrphanPackagesIssue::new(false, vec!["packages/*".into()], vec!["tools/cli".into()]);
+
+        assert_eq!(issue.name(), "orphan-packages");
+        assert_eq!(issue.level(), IssueLevel::Warning);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "All folders with a package.json should be matched by the workspace."
+        );
+    }
+
+    #[test]
+    fn test_pnpm_workspace() {
+        let issue = OrphanPackagesIssue::new(
+            true,
+            vec!["packages/*".into(), "docs".into()],
+            vec!["tools/cli".into(), "website".into()],
+        );
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn append_pnpm() {
+        let source = "# Workspace\npackages:\n  # Apps\n  - 'apps/*'\n  - 'packages/*' # Libraries\n\ncatalog:\n  react: ^18.2.0\n";
+
+        assert_eq!(
+            append_pnpm_packages(source, &["tools/cli".into(), "website".into()]).unwrap(),
+            "# Workspace\npackages:\n  # Apps\n  - 'apps/*'\n  - 'packages/*' # Libraries\n  - 'tools/cli'\n  - 'website'\n\ncatalog:\n  react: ^18.2.0\n"
+        );
+        assert_eq!(
+            append_pnpm_packages("packages:\n- apps/*", &["tools/cli".into()]).unwrap(),
+            "packages:\n- apps/*\n- tools/cli\n"
+        );
+        assert!(append_pnpm_packages("packages: [apps/*]\n", &["tools/cli".into()]).is_none());
+    }
+}
@@ -0,0 +1,109 @@
+use super::{Issue, IssueLevel};
+use crate::lockfile::LockfileKind;
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct OutdatedLockfileIssue {
+    lockfile: LockfileKind,
+    /// The name, declared specifier and locked specifier of each dependency.
+    dependencies: Vec<(String, Option<String>, Option<String>)>,
+}
+
+impl OutdatedLockfileIssue {
+    pub fn new(
+        lockfile: LockfileKind,
+        dependencies: Vec<(String, Option<String>, Option<String>)>,
+    ) -> Box<Self> {
+        Box::new(Self {
+            lockfile,
+            dependencies,
+        })
+    }
+}
+
+impl Issue for OutdatedLockfileIssue {
+    fn name(&self) -> &str {
+        "outdated-lockfile"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        let dependencies = self
+            .dependencies
+            .iter()
+            .map(|(name, declared, locked)| match (declared, locked) {
+                (Some(declared), Some(locked)) => format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "~".yellow(),
+                    name.white(),
+                    declared.yellow(),
+                    format!(r#"← but {} has "{}"."#, self.lockfile, locked).yellow(),
+                ),
+                (Some(declared), None) => format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "+".green(),
+                    name.white(),
+                    declared.green(),
+                    format!("← but it's missing from {}.", self.lockfile).green(),
+                ),
+                (None, locked) => format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "-".red(),
+                    name.white(),
+                    locked.as_deref().unwrap_or_default().red(),
+                    format!("← but it's only in {}.", self.lockfile).red(),
+                ),
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+  │   ...
+{}
+  │   ...
+  │ }}"#,
+            dependencies,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "{} should be up-to-date with the declared dependencies. Run your package manager's install command.",
+            self.lockfile
+        ))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = OutdatedLockfileIssue::new(
+            LockfileKind::Pnpm,
+            vec![
+                ("next".into(), Some("14.1.0".into()), Some("14.0.0".into())),
+                ("zod".into(), Some("^3.22.0".into()), None),
+                ("lodash".into(), None, Some("^4.17.21".into())),
+            ],
+        );
+
+        assert_eq!(issue.name(), "outdated-lockfile");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "pnpm-lock.yaml should be up-to-date with the declared dependencies. Run your package manager's install command."
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use super::{Issue, IssueLevel};
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct PackageFilesFieldIssue {
+    /// The current `files` field, if it isn't an array of paths.
+    files: Option<String>,
+}
+
+impl PackageFilesFieldIssue {
+    pub fn new(files: Option<String>) -> Box<Self> {
+        Box::new(Self { files })
+    }
+}
+
+impl Issue for PackageFilesFieldIssue {
+    fn name(&self) -> &str {
+        "package-files-field"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        match &self.files {
+            Some(files) => format!(
+                r#"  │ {{
+  {}   "{}": {}   {}
+  {}   "{}": [...]   {}
+  │ }}"#,
+                "-".red(),
+                "files".white(),
+                files.white(),
+                "← invalid files field.".red(),
+                "+".green(),
+                "files".white(),
+                "← should be an array of paths.".green(),
+            ),
+            None => format!(
+                r#"  │ {{
+  {}   "{}": [...]   {}
+  │ }}"#,
+                "+".green(),
+                "files".white(),
+                "← missing files field or .npmignore file.".green(),
+            ),
+        }
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Non-private packages should have a `files` field or a `.npmignore` file to avoid publishing unnecessary files.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = PackageFilesFieldIssue::new(None);
+
+        assert_eq!(issue.name(), "package-files-field");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Non-private packages should have a `files` field or a `.npmignore` file to avoid publishing unnecessary files."
+        );
+    }
+
+    #[test]
+    fn invalid_files() {
+        let issue = PackageFilesFieldIssue::new(Some(r#""dist""#.into()));
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+}
@@ -0,0 +1,59 @@
+use super::{Issue, IssueLevel};
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct PackageLicenseFieldIssue;
+
+impl PackageLicenseFieldIssue {
+    pub fn new() -> Box<Self> {
+        Box::new(Self)
+    }
+}
+
+impl Issue for PackageLicenseFieldIssue {
+    fn name(&self) -> &str {
+        "package-license-field"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        format!(
+            r#"  │ {{
+  {}   "{}": "..."   {}
+  │ }}"#,
+            "+".green(),
+            "license".white(),
+            "← missing license field.".green(),
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Non-private packages should specify a license to let users know how they are allowed to use them.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = PackageLicenseFieldIssue::new();
+
+        assert_eq!(issue.name(), "package-license-field");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Non-private packages should specify a license to let users know how they are allowed to use them."
+        );
+    }
+}
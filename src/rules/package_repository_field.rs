@@ -0,0 +1,218 @@
+use super::{FixContext, Issue, IssueLevel, PackageType};
+use anyhow::Result;
+use colored::Colorize;
+use std::{borrow::Cow, path::PathBuf};
+
+#[derive(Debug)]
+pub struct PackageRepositoryFieldIssue {
+    directory: String,
+    has_repository: bool,
+    fixed: bool,
+}
+
+impl PackageRepositoryFieldIssue {
+    pub fn new(directory: String, has_repository: bool) -> Box<Self> {
+        Box::new(Self {
+            directory,
+            has_repository,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for PackageRepositoryFieldIssue {
+    fn name(&self) -> &str {
+        "package-repository-field"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Error,
+        }
+    }
+
+    fn message(&self) -> String {
+        match self.has_repository {
+            true => format!(
+                r#"  │ {{
+  │   "{}": {{
+  │     "{}": "...",
+  {}     "{}": "{}"   {}
+  │   }}
+  │ }}"#,
+                "repository".white(),
+                "url".white(),
+                "+".green(),
+                "directory".white(),
+                self.directory.white(),
+                "← should point to the package directory.".green(),
+            ),
+            false => format!(
+                r#"  │ {{
+  {}   "{}": {{   {}
+  {}     "{}": "...",
+  {}     "{}": "{}"
+  {}   }}
+  │ }}"#,
+                "+".green(),
+                "repository".white(),
+                "← missing repository field.".green(),
+                "+".green(),
+                "url".white(),
+                "+".green(),
+                "directory".white(),
+                self.directory.white(),
+                "+".green(),
+            ),
+        }
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Non-private packages should have a repository field with a directory pointing to the package inside the monorepo.")
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        if let PackageType::Package(path) = package_type {
+            let path = PathBuf::from(path).join("package.json");
+            let mut value = context.edit_json(&path)?;
+            let directory = serde_json::Value::String(self.directory.clone());
+
+            // We can't guess the repository URL, so only fix repositories defined as
+            // objects or as a URL/shorthand string.
+            match value.get_mut("repository") {
+                Some(serde_json::Value::Object(repository)) => {
+                    repository.insert("directory".into(), directory);
+                    self.fixed = true;
+                }
+                Some(repository) => {
+                    if let Some(url) = repository.as_str().and_then(expand_url) {
+                        *repository = serde_json::json!({
+                            "type": "git",
+                            "url": url,
+                            "directory": directory,
+                        });
+                        self.fixed = true;
+                    }
+                }
+                None => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The URL of a repository string, expanding the `user/repo` and
+/// `github:user/repo` shorthands the same way npm does.
+fn expand_url(repository: &str) -> Option<String> {
+    if repository.contains("://") {
+        return Some(repository.to_string());
+    }
+
+    let (host, path) = match repository.split_once(':') {
+        Some(("github", path)) => ("github.com", path),
+        Some(("gitlab", path)) => ("gitlab.com", path),
+        Some(("bitbucket", path)) => ("bitbucket.org", path),
+        Some(_) => return None,
+        None => ("github.com", repository),
+    };
+
+    match path.split_once('/') {
+        Some((user, repo)) if !user.is_empty() && !repo.is_empty() && !repo.contains('/') => {
+            Some(format!("git+https://{}/{}.git", host, path))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fix::EditPlan;
+    use std::path::Path;
+
+    #[test]
+    fn test() {
+        let issue = PackageRepositoryFieldIssue::new("packages/abc".into(), true);
+
+        assert_eq!(issue.name(), "package-repository-field");
+        assert_eq!(issue.level(), IssueLevel::Error);
+        assert_eq!(
+            issue.why(),
+            "Non-private packages should have a repository field with a directory pointing to the package inside the monorepo."
+        );
+    }
+
+    #[test]
+    fn missing_repository() {
+        let issue = PackageRepositoryFieldIssue::new("packages/abc".into(), false);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn missing_directory() {
+        let issue = PackageRepositoryFieldIssue::new("packages/abc".into(), true);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn fix_shorthand() {
+        let files = EditPlan::default();
+        let context = FixContext {
+            root: Path::new("."),
+            interactive: false,
+            files: &files,
+        };
+        let path = Path::new("packages/abc/package.json");
+
+        for (repository, url) in [
+            (
+                "QuiiBz/sherif",
+                Some("git+https://github.com/QuiiBz/sherif.git"),
+            ),
+            (
+                "gitlab:QuiiBz/sherif",
+                Some("git+https://gitlab.com/QuiiBz/sherif.git"),
+            ),
+            (
+                "https://github.com/QuiiBz/sherif",
+                Some("https://github.com/QuiiBz/sherif"),
+            ),
+            ("gist:11081aaa281", None),
+        ] {
+            files.write(path, format!(r#"{{"repository":"{}"}}"#, repository));
+
+            let mut issue = PackageRepositoryFieldIssue::new("packages/abc".into(), true);
+            issue
+                .fix(&PackageType::Package("packages/abc".into()), &context)
+                .unwrap();
+
+            let value = files.edit_json(path).unwrap();
+            match url {
+                Some(url) => {
+                    assert_eq!(issue.level(), IssueLevel::Fixed);
+                    assert_eq!(
+                        value["repository"],
+                        serde_json::json!({
+                            "type": "git",
+                            "url": url,
+                            "directory": "packages/abc",
+                        })
+                    );
+                }
+                // Unknown shorthands are left as is, and reported as not fixed.
+                None => {
+                    assert_eq!(issue.level(), IssueLevel::Error);
+                    assert_eq!(value["repository"], repository);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use super::{Issue, IssueLevel};
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct PackageVersionFieldIssue {
+    version: Option<String>,
+}
+
+impl PackageVersionFieldIssue {
+    pub fn new(version: Option<String>) -> Box<Self> {
+        Box::new(Self { version })
+    }
+}
+
+impl Issue for PackageVersionFieldIssue {
+    fn name(&self) -> &str {
+        "package-version-field"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Error
+    }
+
+    fn message(&self) -> String {
+        match &self.version {
+            Some(version) => format!(
+                r#"  │ {{
+  {}   "{}": "{}"   {}
+  │ }}"#,
+                "~".yellow(),
+                "version".white(),
+                version.white(),
+                "← version isn't valid semver.".yellow(),
+            ),
+            None => format!(
+                r#"  │ {{
+  {}   "{}": "..."   {}
+  │ }}"#,
+                "+".green(),
+                "version".white(),
+                "← missing version field.".green(),
+            ),
+        }
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Non-private packages should have a valid semver version to be published.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = PackageVersionFieldIssue::new(None);
+
+        assert_eq!(issue.name(), "package-version-field");
+        assert_eq!(issue.level(), IssueLevel::Error);
+        assert_eq!(
+            issue.why(),
+            "Non-private packages should have a valid semver version to be published."
+        );
+    }
+
+    #[test]
+    fn missing() {
+        let issue = PackageVersionFieldIssue::new(None);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn invalid() {
+        let issue = PackageVersionFieldIssue::new(Some("1.0".into()));
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+}
@@ -1,6 +1,6 @@
-use super::{Issue, IssueLevel, PackageType};
+use super::{FixContext, Issue, IssueLevel, PackageType};
 use anyhow::Result;
-use std::{borrow::Cow, fs, path::PathBuf};
+use std::{borrow::Cow, path::PathBuf};
 
 #[derive(Debug)]
 pub struct PackagesWithoutPackageJsonIssue {
@@ -37,7 +37,12 @@ impl Issue for PackagesWithoutPackageJsonIssue {
         Cow::Borrowed("All packages matching the workspace should have a package.json file.")
     }
 
-    fn fix(&mut self, _package_type: &PackageType) -> Result<()> {
+    /// Without a package.json, the package can't be scoped to.
+    fn involves_package(&self, _path: &str) -> bool {
+        false
+    }
+
+    fn fix(&mut self, _package_type: &PackageType, context: &FixContext) -> Result<()> {
         let path = PathBuf::from(&self.package).join("package.json");
         let package_name = path
             .parent()
@@ -54,7 +59,7 @@ impl Issue for PackagesWithoutPackageJsonIssue {
         });
 
         let value = serde_json::to_string_pretty(&value)?;
-        fs::write(path, value)?;
+        context.write(&path, value);
 
         self.fixed = true;
 
@@ -1,8 +1,7 @@
-use super::{Issue, IssueLevel, PackageType};
-use crate::json;
+use super::{FixContext, Issue, IssueLevel, PackageType};
 use anyhow::Result;
 use colored::Colorize;
-use std::{borrow::Cow, fs, path::PathBuf};
+use std::borrow::Cow;
 
 #[derive(Debug)]
 pub struct RootPackagePrivateFieldIssue {
@@ -45,20 +44,16 @@ impl Issue for RootPackagePrivateFieldIssue {
         Cow::Borrowed("The root package.json should be private to prevent accidentaly publishing it to a registry.")
     }
 
-    fn fix(&mut self, package_type: &PackageType) -> Result<()> {
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
         if let PackageType::Root = package_type {
-            let path = PathBuf::from("package.json");
-            let value = fs::read_to_string(&path)?;
-            let (mut value, indent, lineending) = json::deserialize::<serde_json::Value>(&value)?;
+            let path = context.root.join("package.json");
+            let mut value = context.edit_json(&path)?;
 
             value
                 .as_object_mut()
                 .unwrap()
                 .insert("private".to_string(), serde_json::Value::Bool(true));
 
-            let value = json::serialize(&value, indent, lineending)?;
-            fs::write(path, value)?;
-
             self.fixed = true;
         }
 
@@ -1,9 +1,8 @@
-use super::{Issue, IssueLevel, PackageType};
-use crate::json;
+use super::{FixContext, Issue, IssueLevel, PackageType};
 use anyhow::Result;
 use colored::Colorize;
 use indexmap::IndexMap;
-use std::{borrow::Cow, fs, path::PathBuf};
+use std::{borrow::Cow, path::PathBuf};
 
 #[derive(Debug)]
 pub struct TypesInDependenciesIssue {
@@ -81,11 +80,10 @@ impl Issue for TypesInDependenciesIssue {
         Cow::Borrowed("Private packages shouldn't have @types/* in dependencies.")
     }
 
-    fn fix(&mut self, package_type: &PackageType) -> Result<()> {
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
         if let PackageType::Package(path) = package_type {
             let path = PathBuf::from(path).join("package.json");
-            let value = fs::read_to_string(&path)?;
-            let (mut value, indent, lineending) = json::deserialize::<serde_json::Value>(&value)?;
+            let mut value = context.edit_json(&path)?;
 
             let dependencies = value
                 .get_mut("dependencies")
@@ -95,11 +93,14 @@ impl Issue for TypesInDependenciesIssue {
             let mut dependencies_to_add = IndexMap::new();
 
             for package in &self.packages {
-                if let Some(version) = dependencies.remove(package) {
-                    dependencies_to_add.insert(package.clone(), version);
+                if let Some(version) = dependencies.get(package) {
+                    dependencies_to_add.insert(package.clone(), version.clone());
                 }
             }
 
+            // `remove` would swap the last dependency in place of the removed one.
+            dependencies.retain(|package, _| !dependencies_to_add.contains_key(package));
+
             // The package.json file might not have a devDependencies field.
             let dev_dependencies = match value.get_mut("devDependencies") {
                 Some(dev_dependencies) => dev_dependencies,
@@ -119,9 +120,6 @@ impl Issue for TypesInDependenciesIssue {
                 dev_dependencies.insert(package, version);
             }
 
-            let value = json::serialize(&value, indent, lineending)?;
-            fs::write(path, value)?;
-
             self.fixed = true;
         }
 
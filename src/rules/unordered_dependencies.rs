@@ -1,8 +1,7 @@
-use super::{empty_dependencies::DependencyKind, Issue, IssueLevel, PackageType};
-use crate::json;
+use super::{empty_dependencies::DependencyKind, FixContext, Issue, IssueLevel, PackageType};
 use anyhow::Result;
 use colored::Colorize;
-use std::{borrow::Cow, fs, path::PathBuf};
+use std::{borrow::Cow, path::PathBuf};
 
 #[derive(Debug)]
 pub struct UnorderedDependenciesIssue {
@@ -18,9 +17,8 @@ impl UnorderedDependenciesIssue {
         })
     }
 
-    pub fn sort(&mut self, path: PathBuf) -> Result<()> {
-        let value = fs::read_to_string(&path)?;
-        let (mut value, indent, lineending) = json::deserialize::<serde_json::Value>(&value)?;
+    pub fn sort(&mut self, path: PathBuf, context: &FixContext) -> Result<()> {
+        let mut value = context.edit_json(&path)?;
         let dependency = self.dependency_kind.to_string();
 
         if let Some(dependency_field) = value.get(&dependency) {
@@ -42,9 +40,6 @@ impl UnorderedDependenciesIssue {
                     .unwrap()
                     .insert(dependency, serde_json::Value::Object(sorted));
 
-                let value = json::serialize(&value, indent, lineending)?;
-                fs::write(path, value)?;
-
                 self.fixed = true;
             }
         }
@@ -87,13 +82,13 @@ impl Issue for UnorderedDependenciesIssue {
         ))
     }
 
-    fn fix(&mut self, package_type: &PackageType) -> Result<()> {
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
         if let PackageType::Package(path) = package_type {
             let path = PathBuf::from(path).join("package.json");
-            self.sort(path)?;
+            self.sort(path, context)?;
         } else if let PackageType::Root = package_type {
-            let path = PathBuf::from("package.json");
-            self.sort(path)?;
+            let path = context.root.join("package.json");
+            self.sort(path, context)?;
         }
 
         Ok(())
@@ -0,0 +1,220 @@
+use super::{FixContext, Issue, IssueLevel, PackageType};
+use anyhow::Result;
+use colored::Colorize;
+use std::{borrow::Cow, path::PathBuf};
+
+/// The default order of top-level keys, based on the conventions of npm and
+/// popular tools like `sort-package-json`. Unknown keys are kept at the end,
+/// in their original order.
+pub const DEFAULT_KEY_ORDER: [&str; 54] = [
+    "$schema",
+    "name",
+    "displayName",
+    "version",
+    "private",
+    "description",
+    "keywords",
+    "homepage",
+    "bugs",
+    "repository",
+    "funding",
+    "license",
+    "author",
+    "maintainers",
+    "contributors",
+    "publisher",
+    "sideEffects",
+    "type",
+    "imports",
+    "exports",
+    "main",
+    "module",
+    "browser",
+    "react-native",
+    "types",
+    "typesVersions",
+    "typings",
+    "bin",
+    "man",
+    "directories",
+    "files",
+    "workspaces",
+    "scripts",
+    "config",
+    "overrides",
+    "resolutions",
+    "dependencies",
+    "devDependencies",
+    "dependenciesMeta",
+    "peerDependencies",
+    "peerDependenciesMeta",
+    "optionalDependencies",
+    "bundledDependencies",
+    "bundleDependencies",
+    "packageManager",
+    "engines",
+    "devEngines",
+    "volta",
+    "os",
+    "cpu",
+    "preferGlobal",
+    "publishConfig",
+    "pnpm",
+    "sherif",
+];
+
+/// Sort keys following the given order, keeping unknown keys at the end.
+pub fn sort_keys<'a>(keys: &[&'a str], order: &[String]) -> Vec<&'a str> {
+    let mut keys = keys.to_vec();
+    // Stable sort, which keeps the original order of unknown keys.
+    keys.sort_by_key(|key| {
+        order
+            .iter()
+            .position(|ordered| ordered == key)
+            .unwrap_or(order.len())
+    });
+
+    keys
+}
+
+#[derive(Debug)]
+pub struct UnorderedPackageKeysIssue {
+    /// The first key that isn't at its expected position.
+    key: String,
+    /// The key it should be before.
+    before: String,
+    order: Vec<String>,
+    fixed: bool,
+}
+
+impl UnorderedPackageKeysIssue {
+    pub fn new(key: String, before: String, order: Vec<String>) -> Box<Self> {
+        Box::new(Self {
+            key,
+            before,
+            order,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for UnorderedPackageKeysIssue {
+    fn name(&self) -> &str {
+        "unordered-package-keys"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Error,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!(
+            r#"  │ {{
+  │   ...
+  {}   "{}": ...   {}
+  │   ...
+  │ }}"#,
+            "~".blue(),
+            self.key.white(),
+            format!(r#"← should be before "{}"."#, self.before).blue(),
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Top-level package.json keys should follow a consistent order.")
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        let path = match package_type {
+            PackageType::Package(path) => PathBuf::from(path).join("package.json"),
+            PackageType::Root => context.root.join("package.json"),
+            PackageType::None => return Ok(()),
+        };
+
+        let mut value = context.edit_json(&path)?;
+
+        if let Some(object) = value.as_object_mut() {
+            let keys = object.keys().map(String::as_str).collect::<Vec<_>>();
+            let keys = sort_keys(&keys, &self.order)
+                .into_iter()
+                .map(str::to_string)
+                .collect::<Vec<_>>();
+
+            let mut sorted = serde_json::Map::new();
+            for key in keys {
+                if let Some(field) = object.get(&key) {
+                    sorted.insert(key, field.clone());
+                }
+            }
+
+            *object = sorted;
+
+            self.fixed = true;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = UnorderedPackageKeysIssue::new(
+            "version".into(),
+            "private".into(),
+            DEFAULT_KEY_ORDER.map(String::from).to_vec(),
+        );
+
+        assert_eq!(issue.name(), "unordered-package-keys");
+        assert_eq!(issue.level(), IssueLevel::Error);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Top-level package.json keys should follow a consistent order."
+        );
+    }
+
+    #[test]
+    fn sort() {
+        let order = DEFAULT_KEY_ORDER.map(String::from).to_vec();
+
+        assert_eq!(
+            sort_keys(
+                &[
+                    "dependencies",
+                    "custom",
+                    "version",
+                    "name",
+                    "other",
+                    "scripts"
+                ],
+                &order
+            ),
+            vec![
+                "name",
+                "version",
+                "scripts",
+                "dependencies",
+                "custom",
+                "other"
+            ]
+        );
+        assert_eq!(
+            sort_keys(
+                &["name", "version"],
+                &["version".to_string(), "name".to_string()]
+            ),
+            vec!["version", "name"]
+        );
+    }
+}
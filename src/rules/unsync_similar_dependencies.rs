@@ -1,209 +1,261 @@
-use super::Issue;
-use crate::packages::semversion::SemVersion;
+use super::{empty_dependencies::DependencyKind, Issue};
+use crate::packages::{matches_dependency, semversion::SemVersion};
 use colored::Colorize;
 use indexmap::IndexMap;
-use std::{borrow::Cow, fmt::Display, hash::Hash};
+use serde::Deserialize;
+use std::borrow::Cow;
 
-#[derive(Debug, Hash, PartialEq, Eq, Clone)]
-pub enum SimilarDependency {
-    Trpc,
-    React,
-    NextJS,
-    Storybook,
-    Turborepo,
-    Prisma,
-    TypescriptEslint,
-    EslintStylistic,
-    Playwright,
-    Lexical,
+/// A group of dependencies released together, which should use the same version.
+/// Dependencies can be exact names or patterns like `@tanstack/*`.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+pub struct SimilarDependency {
+    pub name: String,
+    pub dependencies: Vec<String>,
 }
 
-impl Display for SimilarDependency {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Trpc => write!(f, "tRPC"),
-            Self::React => write!(f, "React"),
-            Self::NextJS => write!(f, "Next.js"),
-            Self::Storybook => write!(f, "Storybook"),
-            Self::Turborepo => write!(f, "Turborepo"),
-            Self::Prisma => write!(f, "Prisma"),
-            Self::TypescriptEslint => write!(f, "typescript-eslint"),
-            Self::EslintStylistic => write!(f, "ESLint Stylistic"),
-            Self::Playwright => write!(f, "Playwright"),
-            Self::Lexical => write!(f, "Lexical"),
-        }
+impl SimilarDependency {
+    pub fn builtins() -> Vec<Self> {
+        BUILTIN_SIMILAR_DEPENDENCIES
+            .iter()
+            .map(|(name, dependencies)| Self {
+                name: name.to_string(),
+                dependencies: dependencies
+                    .iter()
+                    .map(|dependency| dependency.to_string())
+                    .collect(),
+            })
+            .collect()
     }
-}
-
-impl TryFrom<&str> for SimilarDependency {
-    type Error = anyhow::Error;
 
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "@trpc/client" | "@trpc/server" | "@trpc/next" | "@trpc/react-query" => Ok(Self::Trpc),
-            "react" | "react-dom" => Ok(Self::React),
-            "eslint-config-next"
-            | "@next/eslint-plugin-next"
-            | "@next/font"
-            | "@next/bundle-analyzer"
-            | "@next/mdx"
-            | "next"
-            | "@next/third-parties" => Ok(Self::NextJS),
-            "eslint-config-turbo"
-            | "eslint-plugin-turbo"
-            | "@turbo/gen"
-            | "turbo-ignore"
-            | "turbo" => Ok(Self::Turborepo),
-            "sb"
-            | "storybook"
-            | "@storybook/codemod"
-            | "@storybook/cli"
-            | "@storybook/channels"
-            | "@storybook/addon-actions"
-            | "@storybook/addon-links"
-            | "@storybook/react"
-            | "@storybook/react-native"
-            | "@storybook/components"
-            | "@storybook/addon-backgrounds"
-            | "@storybook/addon-viewport"
-            | "@storybook/angular"
-            | "@storybook/addon-a11y"
-            | "@storybook/addon-jest"
-            | "@storybook/client-logger"
-            | "@storybook/node-logger"
-            | "@storybook/core"
-            | "@storybook/addon-storysource"
-            | "@storybook/html"
-            | "@storybook/core-events"
-            | "@storybook/svelte"
-            | "@storybook/ember"
-            | "@storybook/addon-ondevice-backgrounds"
-            | "@storybook/addon-ondevice-notes"
-            | "@storybook/preact"
-            | "@storybook/theming"
-            | "@storybook/router"
-            | "@storybook/addon-docs"
-            | "@storybook/addon-ondevice-actions"
-            | "@storybook/source-loader"
-            | "@storybook/preset-create-react-app"
-            | "@storybook/web-components"
-            | "@storybook/addon-essentials"
-            | "@storybook/server"
-            | "@storybook/addon-toolbars"
-            | "@storybook/addon-controls"
-            | "@storybook/core-common"
-            | "@storybook/builder-webpack5"
-            | "@storybook/core-server"
-            | "@storybook/csf-tools"
-            | "@storybook/addon-measure"
-            | "@storybook/addon-outline"
-            | "@storybook/addon-ondevice-controls"
-            | "@storybook/instrumenter"
-            | "@storybook/addon-interactions"
-            | "@storybook/docs-tools"
-            | "@storybook/builder-vite"
-            | "@storybook/telemetry"
-            | "@storybook/core-webpack"
-            | "@storybook/preset-html-webpack"
-            | "@storybook/preset-preact-webpack"
-            | "@storybook/preset-svelte-webpack"
-            | "@storybook/preset-react-webpack"
-            | "@storybook/html-webpack5"
-            | "@storybook/preact-webpack5"
-            | "@storybook/svelte-webpack5"
-            | "@storybook/web-components-webpack5"
-            | "@storybook/preset-server-webpack"
-            | "@storybook/react-webpack5"
-            | "@storybook/server-webpack5"
-            | "@storybook/addon-highlight"
-            | "@storybook/blocks"
-            | "@storybook/builder-manager"
-            | "@storybook/react-vite"
-            | "@storybook/svelte-vite"
-            | "@storybook/web-components-vite"
-            | "@storybook/nextjs"
-            | "@storybook/types"
-            | "@storybook/manager"
-            | "@storybook/csf-plugin"
-            | "@storybook/preview"
-            | "@storybook/manager-api"
-            | "@storybook/preview-api"
-            | "@storybook/html-vite"
-            | "@storybook/sveltekit"
-            | "@storybook/preact-vite"
-            | "@storybook/addon-mdx-gfm"
-            | "@storybook/react-dom-shim"
-            | "create-storybook"
-            | "@storybook/addon-onboarding"
-            | "@storybook/react-native-theming"
-            | "@storybook/addon-themes"
-            | "@storybook/test"
-            | "@storybook/react-native-ui"
-            | "@storybook/experimental-nextjs-vite"
-            | "@storybook/experimental-addon-test"
-            | "@storybook/react-native-web-vite" => Ok(Self::Storybook),
-            "prisma"
-            | "@prisma/client"
-            | "@prisma/instrumentation"
-            | "@prisma/adapter-pg"
-            | "@prisma/adapter-neon"
-            | "@prisma/adapter-planetscale"
-            | "@prisma/adapter-d1"
-            | "@prisma/adapter-libsql"
-            | "@prisma/adapter-pg-worker"
-            | "@prisma/pg-worker" => Ok(Self::Prisma),
-            "typescript-eslint"
-            | "@typescript-eslint/eslint-plugin"
-            | "@typescript-eslint/parser" => Ok(Self::TypescriptEslint),
-            "@stylistic/eslint-plugin-js"
-            | "@stylistic/eslint-plugin-ts"
-            | "@stylistic/eslint-plugin-migrate"
-            | "@stylistic/eslint-plugin"
-            | "@stylistic/eslint-plugin-jsx"
-            | "@stylistic/eslint-plugin-plus" => Ok(Self::EslintStylistic),
-            "playwright" | "@playwright/test" => Ok(Self::Playwright),
-            "lexical"
-            | "@lexical/clipboard"
-            | "@lexical/code"
-            | "@lexical/devtools-core"
-            | "@lexical/dragon"
-            | "@lexical/eslint-plugin"
-            | "@lexical/file"
-            | "@lexical/hashtag"
-            | "@lexical/headless"
-            | "@lexical/history"
-            | "@lexical/html"
-            | "@lexical/link"
-            | "@lexical/list"
-            | "@lexical/mark"
-            | "@lexical/markdown"
-            | "@lexical/offset"
-            | "@lexical/overflow"
-            | "@lexical/plain-text"
-            | "@lexical/react"
-            | "@lexical/rich-text"
-            | "@lexical/selection"
-            | "@lexical/table"
-            | "@lexical/text"
-            | "@lexical/utils"
-            | "@lexical/yjs" => Ok(Self::Lexical),
-            _ => Err(anyhow::anyhow!("Unknown similar dependency")),
-        }
+    pub fn matches(&self, dependency: &str) -> bool {
+        self.dependencies
+            .iter()
+            .any(|pattern| matches_dependency(pattern, dependency))
     }
 }
 
+const BUILTIN_SIMILAR_DEPENDENCIES: [(&str, &[&str]); 10] = [
+    (
+        "tRPC",
+        &[
+            "@trpc/client",
+            "@trpc/server",
+            "@trpc/next",
+            "@trpc/react-query",
+        ],
+    ),
+    ("React", &["react", "react-dom"]),
+    (
+        "Next.js",
+        &[
+            "eslint-config-next",
+            "@next/eslint-plugin-next",
+            "@next/font",
+            "@next/bundle-analyzer",
+            "@next/mdx",
+            "next",
+            "@next/third-parties",
+        ],
+    ),
+    (
+        "Storybook",
+        &[
+            "sb",
+            "storybook",
+            "@storybook/codemod",
+            "@storybook/cli",
+            "@storybook/channels",
+            "@storybook/addon-actions",
+            "@storybook/addon-links",
+            "@storybook/react",
+            "@storybook/react-native",
+            "@storybook/components",
+            "@storybook/addon-backgrounds",
+            "@storybook/addon-viewport",
+            "@storybook/angular",
+            "@storybook/addon-a11y",
+            "@storybook/addon-jest",
+            "@storybook/client-logger",
+            "@storybook/node-logger",
+            "@storybook/core",
+            "@storybook/addon-storysource",
+            "@storybook/html",
+            "@storybook/core-events",
+            "@storybook/svelte",
+            "@storybook/ember",
+            "@storybook/addon-ondevice-backgrounds",
+            "@storybook/addon-ondevice-notes",
+            "@storybook/preact",
+            "@storybook/theming",
+            "@storybook/router",
+            "@storybook/addon-docs",
+            "@storybook/addon-ondevice-actions",
+            "@storybook/source-loader",
+            "@storybook/preset-create-react-app",
+            "@storybook/web-components",
+            "@storybook/addon-essentials",
+            "@storybook/server",
+            "@storybook/addon-toolbars",
+            "@storybook/addon-controls",
+            "@storybook/core-common",
+            "@storybook/builder-webpack5",
+            "@storybook/core-server",
+            "@storybook/csf-tools",
+            "@storybook/addon-measure",
+            "@storybook/addon-outline",
+            "@storybook/addon-ondevice-controls",
+            "@storybook/instrumenter",
+            "@storybook/addon-interactions",
+            "@storybook/docs-tools",
+            "@storybook/builder-vite",
+            "@storybook/telemetry",
+            "@storybook/core-webpack",
+            "@storybook/preset-html-webpack",
+            "@storybook/preset-preact-webpack",
+            "@storybook/preset-svelte-webpack",
+            "@storybook/preset-react-webpack",
+            "@storybook/html-webpack5",
+            "@storybook/preact-webpack5",
+            "@storybook/svelte-webpack5",
+            "@storybook/web-components-webpack5",
+            "@storybook/preset-server-webpack",
+            "@storybook/react-webpack5",
+            "@storybook/server-webpack5",
+            "@storybook/addon-highlight",
+            "@storybook/blocks",
+            "@storybook/builder-manager",
+            "@storybook/react-vite",
+            "@storybook/svelte-vite",
+            "@storybook/web-components-vite",
+            "@storybook/nextjs",
+            "@storybook/types",
+            "@storybook/manager",
+            "@storybook/csf-plugin",
+            "@storybook/preview",
+            "@storybook/manager-api",
+            "@storybook/preview-api",
+            "@storybook/html-vite",
+            "@storybook/sveltekit",
+            "@storybook/preact-vite",
+            "@storybook/addon-mdx-gfm",
+            "@storybook/react-dom-shim",
+            "create-storybook",
+            "@storybook/addon-onboarding",
+            "@storybook/react-native-theming",
+            "@storybook/addon-themes",
+            "@storybook/test",
+            "@storybook/react-native-ui",
+            "@storybook/experimental-nextjs-vite",
+            "@storybook/experimental-addon-test",
+            "@storybook/react-native-web-vite",
+        ],
+    ),
+    (
+        "Turborepo",
+        &[
+            "eslint-config-turbo",
+            "eslint-plugin-turbo",
+            "@turbo/gen",
+            "turbo-ignore",
+            "turbo",
+        ],
+    ),
+    (
+        "Prisma",
+        &[
+            "prisma",
+            "@prisma/client",
+            "@prisma/instrumentation",
+            "@prisma/adapter-pg",
+            "@prisma/adapter-neon",
+            "@prisma/adapter-planetscale",
+            "@prisma/adapter-d1",
+            "@prisma/adapter-libsql",
+            "@prisma/adapter-pg-worker",
+            "@prisma/pg-worker",
+        ],
+    ),
+    (
+        "typescript-eslint",
+        &[
+            "typescript-eslint",
+            "@typescript-eslint/eslint-plugin",
+            "@typescript-eslint/parser",
+        ],
+    ),
+    (
+        "ESLint Stylistic",
+        &[
+            "@stylistic/eslint-plugin-js",
+            "@stylistic/eslint-plugin-ts",
+            "@stylistic/eslint-plugin-migrate",
+            "@stylistic/eslint-plugin",
+            "@stylistic/eslint-plugin-jsx",
+            "@stylistic/eslint-plugin-plus",
+        ],
+    ),
+    ("Playwright", &["playwright", "@playwright/test"]),
+    (
+        "Lexical",
+        &[
+            "lexical",
+            "@lexical/clipboard",
+            "@lexical/code",
+            "@lexical/devtools-core",
+            "@lexical/dragon",
+            "@lexical/eslint-plugin",
+            "@lexical/file",
+            "@lexical/hashtag",
+            "@lexical/headless",
+            "@lexical/history",
+            "@lexical/html",
+            "@lexical/link",
+            "@lexical/list",
+            "@lexical/mark",
+            "@lexical/markdown",
+            "@lexical/offset",
+            "@lexical/overflow",
+            "@lexical/plain-text",
+            "@lexical/react",
+            "@lexical/rich-text",
+            "@lexical/selection",
+            "@lexical/table",
+            "@lexical/text",
+            "@lexical/utils",
+            "@lexical/yjs",
+        ],
+    ),
+];
+
+/// A dependency of a group, with its version, where it's declared and the
+/// packages using it.
+pub type WorkspaceSimilarDependency = (String, SemVersion, DependencyKind, Vec<String>);
+
 #[derive(Debug)]
 pub struct UnsyncSimilarDependenciesIssue {
-    r#type: SimilarDependency,
+    r#type: String,
     versions: IndexMap<SemVersion, String>,
+    /// Dependencies across the workspace, when checking the whole workspace
+    /// instead of each package.
+    workspace: Vec<WorkspaceSimilarDependency>,
     fixed: bool,
 }
 
 impl UnsyncSimilarDependenciesIssue {
-    pub fn new(r#type: SimilarDependency, versions: IndexMap<SemVersion, String>) -> Box<Self> {
+    pub fn new(r#type: String, versions: IndexMap<SemVersion, String>) -> Box<Self> {
         Box::new(Self {
             r#type,
             versions,
+            workspace: Vec::new(),
+            fixed: false,
+        })
+    }
+
+    pub fn new_workspace(r#type: String, workspace: Vec<WorkspaceSimilarDependency>) -> Box<Self> {
+        Box::new(Self {
+            r#type,
+            versions: IndexMap::new(),
+            workspace,
             fixed: false,
         })
     }
@@ -222,27 +274,65 @@ impl Issue for UnsyncSimilarDependenciesIssue {
     }
 
     fn message(&self) -> String {
-        let deps = self
-            .versions
+        let deps = match self.workspace.is_empty() {
+            true => format!(
+                r#"  │   "{}": {{
+{}
+  │   }}"#,
+                "dependencies".white(),
+                self.versions
+                    .iter()
+                    .map(|(version, dependency)| {
+                        format!(
+                            r#"  {}      "{}": "{}""#,
+                            "~".yellow(),
+                            dependency.white(),
+                            version.to_string().yellow()
+                        )
+                    })
+                    .collect::<Vec<String>>()
+                    .join(",\n"),
+            ),
+            false => [
+                DependencyKind::Dependencies,
+                DependencyKind::DevDependencies,
+                DependencyKind::OptionalDependencies,
+            ]
             .iter()
-            .map(|(version, dependency)| {
-                format!(
-                    r#"  {}      "{}": "{}""#,
-                    "~".yellow(),
-                    dependency.white(),
-                    version.to_string().yellow()
-                )
+            .filter_map(|dependency_kind| {
+                let dependencies = self
+                    .workspace
+                    .iter()
+                    .filter(|(_, _, kind, _)| kind == dependency_kind)
+                    .map(|(dependency, version, _, paths)| {
+                        format!(
+                            r#"  {}      "{}": "{}"   {}"#,
+                            "~".yellow(),
+                            dependency.white(),
+                            version.to_string().yellow(),
+                            format!("← {}", paths.join(", ")).yellow(),
+                        )
+                    })
+                    .collect::<Vec<String>>();
+
+                (!dependencies.is_empty()).then(|| {
+                    format!(
+                        r#"  │   "{}": {{
+{}
+  │   }}"#,
+                        dependency_kind.to_string().white(),
+                        dependencies.join("\n"),
+                    )
+                })
             })
             .collect::<Vec<String>>()
-            .join(",\n");
+            .join("\n"),
+        };
 
         format!(
             r#"  │ {{
-  │   "{}": {{
 {}
-  │   }}
   │ }}"#,
-            "dependencies".white(),
             deps,
         )
         .bright_black()
@@ -256,7 +346,17 @@ impl Issue for UnsyncSimilarDependenciesIssue {
         ))
     }
 
-    fn fix(&mut self, _package_type: &super::PackageType) -> anyhow::Result<()> {
+    fn involves_package(&self, path: &str) -> bool {
+        self.workspace
+            .iter()
+            .any(|(_, _, _, paths)| paths.iter().any(|current| current == path))
+    }
+
+    fn fix(
+        &mut self,
+        _package_type: &super::PackageType,
+        _context: &super::FixContext,
+    ) -> anyhow::Result<()> {
         Ok(())
     }
 }
@@ -275,7 +375,7 @@ mod tests {
         .into_iter()
         .collect();
 
-        let issue = UnsyncSimilarDependenciesIssue::new(SimilarDependency::React, versions);
+        let issue = UnsyncSimilarDependenciesIssue::new("React".into(), versions);
 
         assert_eq!(issue.name(), "unsync-similar-dependencies");
         assert_eq!(issue.level(), IssueLevel::Error);
@@ -295,7 +395,56 @@ mod tests {
         .into_iter()
         .collect();
 
-        let issue = UnsyncSimilarDependenciesIssue::new(SimilarDependency::React, versions);
+        let issue = UnsyncSimilarDependenciesIssue::new("React".into(), versions);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+    }
+
+    #[test]
+    fn builtins() {
+        let builtins = SimilarDependency::builtins();
+        let find = |dependency: &str| {
+            builtins
+                .iter()
+                .find(|similar_dependency| similar_dependency.matches(dependency))
+                .map(|similar_dependency| similar_dependency.name.as_str())
+        };
+
+        assert_eq!(find("react-dom"), Some("React"));
+        assert_eq!(find("@storybook/react-vite"), Some("Storybook"));
+        assert_eq!(find("@trpc/server"), Some("tRPC"));
+        assert_eq!(find("vue"), None);
+    }
+
+    #[test]
+    fn workspace() {
+        let issue = UnsyncSimilarDependenciesIssue::new_workspace(
+            "tRPC".into(),
+            vec![
+                (
+                    "@trpc/client".into(),
+                    SemVersion::parse("10.0.0").unwrap(),
+                    DependencyKind::Dependencies,
+                    vec!["packages/abc".into()],
+                ),
+                (
+                    "@trpc/server".into(),
+                    SemVersion::parse("11.0.0").unwrap(),
+                    DependencyKind::Dependencies,
+                    vec!["packages/def".into()],
+                ),
+                (
+                    "@trpc/server".into(),
+                    SemVersion::parse("11.0.0").unwrap(),
+                    DependencyKind::DevDependencies,
+                    vec!["packages/ghi".into()],
+                ),
+            ],
+        );
+
+        assert!(issue.involves_package("packages/ghi"));
+        assert!(!issue.involves_package("packages/jkl"));
 
         colored::control::set_override(false);
         insta::assert_snapshot!(issue.message());
@@ -0,0 +1,96 @@
+use super::{Issue, IssueLevel};
+use colored::Colorize;
+use std::borrow::Cow;
+
+/// Dependencies that are used without being imported, e.g. config-only tools
+/// or helpers injected by compilers.
+pub const DEFAULT_ALLOWED: [&str; 10] = [
+    "typescript",
+    "tslib",
+    "@types/node",
+    "eslint",
+    "eslint-config-*",
+    "eslint-plugin-*",
+    "@typescript-eslint/*",
+    "prettier",
+    "prettier-plugin-*",
+    "@biomejs/biome",
+];
+
+#[derive(Debug)]
+pub struct UnusedDependenciesIssue {
+    /// The name and version of each dependency.
+    dependencies: Vec<(String, String)>,
+}
+
+impl UnusedDependenciesIssue {
+    pub fn new(dependencies: Vec<(String, String)>) -> Box<Self> {
+        Box::new(Self { dependencies })
+    }
+}
+
+impl Issue for UnusedDependenciesIssue {
+    fn name(&self) -> &str {
+        "unused-dependencies"
+    }
+
+    fn level(&self) -> IssueLevel {
+        IssueLevel::Warning
+    }
+
+    fn message(&self) -> String {
+        let dependencies = self
+            .dependencies
+            .iter()
+            .map(|(name, version)| {
+                format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "-".red(),
+                    name.white(),
+                    version.white(),
+                    "← isn't imported anywhere.".red(),
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!(
+            r#"  │ {{
+  │   "{}": {{
+{}
+  │   }}
+  │ }}"#,
+            "dependencies".white(),
+            dependencies,
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Dependencies should be imported by the package's source files, otherwise they should be removed.")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let issue = UnusedDependenciesIssue::new(vec![
+            ("lodash".into(), "^4.17.21".into()),
+            ("@scope/utils".into(), "workspace:*".into()),
+        ]);
+
+        assert_eq!(issue.name(), "unused-dependencies");
+        assert_eq!(issue.level(), IssueLevel::Warning);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Dependencies should be imported by the package's source files, otherwise they should be removed."
+        );
+    }
+}
@@ -0,0 +1,176 @@
+use super::{FixContext, Issue, IssueLevel, PackageType};
+use crate::packages::overrides::Override;
+use anyhow::Result;
+use colored::Colorize;
+use std::borrow::Cow;
+
+#[derive(Debug)]
+pub struct UnusedOverridesIssue {
+    overrides: Vec<Override>,
+    fixed: bool,
+}
+
+impl UnusedOverridesIssue {
+    pub fn new(overrides: Vec<Override>) -> Box<Self> {
+        Box::new(Self {
+            overrides,
+            fixed: false,
+        })
+    }
+}
+
+impl Issue for UnusedOverridesIssue {
+    fn name(&self) -> &str {
+        "unused-overrides"
+    }
+
+    fn level(&self) -> IssueLevel {
+        match self.fixed {
+            true => IssueLevel::Fixed,
+            false => IssueLevel::Warning,
+        }
+    }
+
+    fn message(&self) -> String {
+        let mut fields = Vec::new();
+        let mut overrides = self.overrides.iter().peekable();
+
+        while let Some(item) = overrides.peek() {
+            let field = item.field;
+            let mut lines = Vec::new();
+
+            while let Some(item) = overrides.next_if(|item| item.field == field) {
+                lines.push(format!(
+                    r#"  {}     "{}": "{}"   {}"#,
+                    "-".red(),
+                    item.key.white(),
+                    item.version.white(),
+                    "← isn't used by any package.".red(),
+                ));
+            }
+
+            fields.push(format!(
+                r#"  │   "{}": {{
+{}
+  │   }}"#,
+                field.to_string().white(),
+                lines.join("\n"),
+            ));
+        }
+
+        format!(
+            r#"  │ {{
+{}
+  │ }}"#,
+            fields.join("\n"),
+        )
+        .bright_black()
+        .to_string()
+    }
+
+    fn why(&self) -> Cow<'static, str> {
+        Cow::Borrowed("Overrides should only target dependencies that are still installed.")
+    }
+
+    fn fix(&mut self, package_type: &PackageType, context: &FixContext) -> Result<()> {
+        if let PackageType::Root = package_type {
+            let path = context.root.join("package.json");
+            let mut value = context.edit_json(&path)?;
+
+            for item in &self.overrides {
+                let field = item
+                    .field
+                    .path()
+                    .iter()
+                    .try_fold(&mut *value, |value, key| value.get_mut(*key));
+
+                if let Some(field) = field.and_then(|field| field.as_object_mut()) {
+                    // Keep the order of the remaining overrides.
+                    match field.get_mut(&item.key) {
+                        // A nested npm override only overrides the package
+                        // itself with `.`, the other entries are still used.
+                        Some(serde_json::Value::Object(nested)) => {
+                            nested.retain(|key, _| key != ".");
+                        }
+                        _ => field.retain(|key, _| key != &item.key),
+                    }
+                }
+            }
+
+            self.fixed = true;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fix::EditPlan;
+    use crate::packages::overrides::OverridesField;
+    use std::path::Path;
+
+    #[test]
+    fn test() {
+        let issue = UnusedOverridesIssue::new(vec![Override {
+            field: OverridesField::Npm,
+            key: "left-pad".into(),
+            name: "left-pad".into(),
+            version: "1.3.0".into(),
+            range: None,
+            top_level: true,
+        }]);
+
+        assert_eq!(issue.name(), "unused-overrides");
+        assert_eq!(issue.level(), IssueLevel::Warning);
+
+        colored::control::set_override(false);
+        insta::assert_snapshot!(issue.message());
+        assert_eq!(
+            issue.why(),
+            "Overrides should only target dependencies that are still installed."
+        );
+    }
+
+    #[test]
+    fn fix_nested() {
+        let mut issue = UnusedOverridesIssue::new(vec![
+            Override {
+                field: OverridesField::Npm,
+                key: "left-pad".into(),
+                name: "left-pad".into(),
+                version: "1.3.0".into(),
+                range: None,
+                top_level: true,
+            },
+            Override {
+                field: OverridesField::Npm,
+                key: "foo".into(),
+                name: "foo".into(),
+                version: "1.0.0".into(),
+                range: None,
+                top_level: true,
+            },
+        ]);
+        let files = EditPlan::default();
+        let context = FixContext {
+            root: Path::new("."),
+            interactive: false,
+            files: &files,
+        };
+        files.write(
+            Path::new("./package.json"),
+            r#"{"overrides":{"left-pad":"1.3.0","foo":{".":"1.0.0","bar":"2.0.0"}}}"#.into(),
+        );
+
+        issue.fix(&PackageType::Root, &context).unwrap();
+        assert_eq!(issue.level(), IssueLevel::Fixed);
+
+        let value = files.edit_json(Path::new("./package.json")).unwrap();
+        assert_eq!(
+            value["overrides"],
+            serde_json::json!({ "foo": { "bar": "2.0.0" } })
+        );
+    }
+}